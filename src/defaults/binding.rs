@@ -0,0 +1,234 @@
+//! Implements two-way bindings between `UserDefaults` and controls - see `UserDefaults::bind()`.
+//!
+//! A binding is an `NSObject` subclass that does two jobs: it acts as the control's target, so
+//! that user changes are written to the defaults, and it observes the defaults key (via KVO), so
+//! that changes made elsewhere (another window, `defaults write`, etc) are reflected in the
+//! control.
+
+use std::sync::Once;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::{Id, ShareId};
+
+use crate::foundation::{id, nil, to_bool, BOOL, YES, NO, NSInteger, NSString};
+use crate::input::TextField;
+use crate::layout::Layout;
+use crate::switch::Switch;
+
+static BINDING_CONTROL: &str = "rstBindingControl";
+static BINDING_DEFAULTS: &str = "rstBindingDefaults";
+static BINDING_KEY: &str = "rstBindingKey";
+
+/// Keeps `Bindable` from being implemented outside this crate, since the binding relies on the
+/// backing control responding to either the `NSTextField` or `NSButton` value methods.
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::switch::Switch {}
+    impl<T> Sealed for crate::input::TextField<T> {}
+}
+
+/// Implemented for controls that can be bound to a default with `UserDefaults::bind()`. This is
+/// sealed; it can't be implemented for other types.
+///
+/// - `Switch` is bound to a boolean value.
+/// - `TextField` is bound to a string value.
+pub trait Bindable: Layout + private::Sealed {}
+
+impl Bindable for Switch {}
+impl<T> Bindable for TextField<T> {}
+
+/// A live binding between a defaults key and a control, as returned from `UserDefaults::bind()`.
+///
+/// The binding lasts for as long as you hold on to this; when it drops, the key is no longer
+/// observed and the control no longer writes back to the defaults. You should keep it alongside
+/// the control it binds (e.g, in the same struct), and drop it before (or along with) the control.
+#[derive(Debug)]
+pub struct DefaultsBinding {
+    objc: Id<Object>,
+    control: ShareId<Object>
+}
+
+impl DefaultsBinding {
+    /// Creates the binding, pushes the current value of the default into the control, and starts
+    /// observing both ends.
+    pub(crate) fn new<V: Bindable>(defaults: &Object, key: &str, control: &V) -> Self {
+        let key = NSString::new(key);
+
+        let control: ShareId<Object> = control.get_from_backing_node(|obj| unsafe {
+            ShareId::from_ptr(msg_send![obj, self])
+        });
+
+        let objc = unsafe {
+            let binding: id = msg_send![register_binding_class(), new];
+            let key_copy: id = msg_send![&*key, copy];
+
+            (&mut *binding).set_ivar(BINDING_CONTROL, &*control as *const Object as id);
+            (&mut *binding).set_ivar(BINDING_DEFAULTS, defaults as *const Object as id);
+            (&mut *binding).set_ivar(BINDING_KEY, key_copy);
+
+            // The defaults object is retained here (and released in dealloc) since the
+            // `UserDefaults` the binding was created from may well be a temporary.
+            let _: id = msg_send![defaults, retain];
+
+            let _: () = msg_send![&*control, setTarget:binding];
+            let _: () = msg_send![&*control, setAction:sel!(controlDidChange:)];
+
+            // NSKeyValueObservingOptionNew
+            let _: () = msg_send![defaults, addObserver:binding forKeyPath:&*key options:1 as usize context:nil];
+
+            Id::from_retained_ptr(binding)
+        };
+
+        update_control(&objc);
+
+        DefaultsBinding {
+            objc,
+            control
+        }
+    }
+}
+
+impl Drop for DefaultsBinding {
+    /// Stops observing the defaults key and detaches from the control. The control is left as-is,
+    /// with whatever value it had.
+    fn drop(&mut self) {
+        unsafe {
+            let defaults: id = *self.objc.get_ivar(BINDING_DEFAULTS);
+            let key: id = *self.objc.get_ivar(BINDING_KEY);
+            let _: () = msg_send![defaults, removeObserver:&*self.objc forKeyPath:key];
+
+            let target: id = msg_send![&*self.control, target];
+            if target == &*self.objc as *const Object as id {
+                let _: () = msg_send![&*self.control, setTarget:nil];
+                let _: () = msg_send![&*self.control, setAction:nil];
+            }
+
+            // Any main-thread update that's still in flight checks for this and bails.
+            self.objc.set_ivar(BINDING_CONTROL, nil);
+        }
+    }
+}
+
+/// Returns `true` if the control is a text field; otherwise it's treated as a switch.
+fn is_text_field(control: id) -> bool {
+    let result: BOOL = unsafe { msg_send![control, isKindOfClass:class!(NSTextField)] };
+    to_bool(result)
+}
+
+/// Reads the current value of the default and pushes it into the control.
+fn update_control(binding: &Object) {
+    unsafe {
+        let control: id = *binding.get_ivar(BINDING_CONTROL);
+        let defaults: id = *binding.get_ivar(BINDING_DEFAULTS);
+        let key: id = *binding.get_ivar(BINDING_KEY);
+
+        if control == nil {
+            return;
+        }
+
+        if is_text_field(control) {
+            let value: id = msg_send![defaults, stringForKey:key];
+            let value = match value {
+                value if value == nil => NSString::new(""),
+                value => NSString::retain(value)
+            };
+
+            let _: () = msg_send![control, setStringValue:&*value];
+        } else {
+            let value: BOOL = msg_send![defaults, boolForKey:key];
+            let state: NSInteger = match to_bool(value) {
+                true => 1,
+                false => 0
+            };
+
+            let _: () = msg_send![control, setState:state];
+        }
+    }
+}
+
+/// Fires when the user changes the control, and writes the new value to the defaults.
+extern fn control_did_change(this: &Object, _: Sel, _sender: id) {
+    unsafe {
+        let control: id = *this.get_ivar(BINDING_CONTROL);
+        let defaults: id = *this.get_ivar(BINDING_DEFAULTS);
+        let key: id = *this.get_ivar(BINDING_KEY);
+
+        if control == nil {
+            return;
+        }
+
+        if is_text_field(control) {
+            let value: id = msg_send![control, stringValue];
+            let _: () = msg_send![defaults, setObject:value forKey:key];
+        } else {
+            let state: NSInteger = msg_send![control, state];
+            let _: () = msg_send![defaults, setBool:match state {
+                0 => NO,
+                _ => YES
+            } forKey:key];
+        }
+    }
+}
+
+/// Fires when the observed default changes. KVO notifications arrive on whatever thread made the
+/// change, so if that's not the main thread we hop over to it before touching the control.
+extern fn observe_value(this: &Object, _: Sel, _key_path: id, _object: id, _change: id, _context: id) {
+    let is_main_thread: BOOL = unsafe { msg_send![class!(NSThread), isMainThread] };
+
+    if to_bool(is_main_thread) {
+        update_control(this);
+        return;
+    }
+
+    // Raw pointers aren't `Send`, so we smuggle the (retained) binding over as an address.
+    let binding: usize = unsafe {
+        let binding: id = msg_send![this, retain];
+        binding as usize
+    };
+
+    dispatch::Queue::main().exec_async(move || unsafe {
+        let binding: Id<Object> = Id::from_retained_ptr(binding as id);
+        update_control(&binding);
+    });
+}
+
+/// Releases the retained defaults object and key.
+extern fn dealloc(this: &Object, _: Sel) {
+    unsafe {
+        let defaults: id = *this.get_ivar(BINDING_DEFAULTS);
+        let key: id = *this.get_ivar(BINDING_KEY);
+        let _: () = msg_send![defaults, release];
+        let _: () = msg_send![key, release];
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers the `NSObject` subclass that backs `DefaultsBinding`.
+fn register_binding_class() -> *const Class {
+    static mut BINDING_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        let mut decl = ClassDecl::new("RSTDefaultsBinding", superclass).unwrap();
+
+        decl.add_ivar::<id>(BINDING_CONTROL);
+        decl.add_ivar::<id>(BINDING_DEFAULTS);
+        decl.add_ivar::<id>(BINDING_KEY);
+
+        decl.add_method(sel!(controlDidChange:), control_did_change as extern fn(&Object, _, id));
+        decl.add_method(
+            sel!(observeValueForKeyPath:ofObject:change:context:),
+            observe_value as extern fn(&Object, _, id, id, id, id)
+        );
+        decl.add_method(sel!(dealloc), dealloc as extern fn(&Object, _));
+
+        BINDING_CLASS = decl.register();
+    });
+
+    unsafe { BINDING_CLASS }
+}
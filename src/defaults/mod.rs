@@ -45,6 +45,12 @@ use crate::foundation::{
 mod value;
 pub use value::Value;
 
+#[cfg(target_os = "macos")]
+mod binding;
+
+#[cfg(target_os = "macos")]
+pub use binding::{Bindable, DefaultsBinding};
+
 /// Wraps and provides methods for interacting with `NSUserDefaults`, which can be used for storing
 /// pieces of information (preferences, or _defaults_) to persist across application launches.
 ///
@@ -249,6 +255,31 @@ impl UserDefaults {
         to_bool(result)
     }
 
+    /// Binds the default for `key` to a control, in both directions: when the user changes the
+    /// control, the new value is stored, and when the stored value changes (from anywhere - another
+    /// window, another process, `defaults write`...) the control is updated to match. The control
+    /// is set to the current value right away.
+    ///
+    /// A `Switch` is bound to a boolean value, and a `TextField` to a string (written when the
+    /// user commits an edit, e.g by pressing Return or leaving the field).
+    ///
+    /// The binding is active for as long as the returned `DefaultsBinding` is alive, so hold on to
+    /// it alongside the control. Note that the binding takes over the control's target/action, so
+    /// it replaces any action you've set on it (and vice versa). Keys containing a `.` can't be
+    /// observed, and won't update the control when changed externally.
+    ///
+    /// ```rust,no_run
+    /// use cacao::defaults::UserDefaults;
+    /// use cacao::switch::Switch;
+    ///
+    /// let switch = Switch::new("Show hidden files");
+    /// let binding = UserDefaults::standard().bind("ShowHiddenFiles", &switch);
+    /// ```
+    #[cfg(target_os = "macos")]
+    pub fn bind<K: AsRef<str>, V: Bindable>(&self, key: K, control: &V) -> DefaultsBinding {
+        DefaultsBinding::new(&*self.0, key.as_ref(), control)
+    }
+
     /// Blocks for any asynchronous updates to the defaults database and returns.
     ///
     /// This method is legacy, likely unnecessary and shouldn't be used unless you know exactly why
@@ -2,12 +2,15 @@
 //! across the codebase, hence why they're here - they're not currently exhaustive, so feel free to
 //! tinker and pull request.
 
+use std::path::PathBuf;
+
 use objc::runtime::Object;
-use objc::{msg_send, sel, sel_impl};
+use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
+use url::Url;
 
-use crate::foundation::NSUInteger;
-use crate::pasteboard::Pasteboard;
+use crate::foundation::{id, nil, to_bool, BOOL, NSArray, NSString, NSUInteger};
+use crate::pasteboard::{Pasteboard, PasteboardType};
 
 /// Represents operations that can happen for a given drag/drop scenario.
 #[derive(Copy, Clone, Debug)]
@@ -52,6 +55,20 @@ impl From<DragOperation> for NSUInteger {
     }
 }
 
+/// An item that's been dropped on a view - e.g, a file dragged in from Finder, or a link or
+/// selection of text dragged from another application.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DropItem {
+    /// A file (or folder) on disk.
+    File(PathBuf),
+
+    /// A non-file URL, e.g a link dragged from a browser.
+    Url(Url),
+
+    /// A plain text string.
+    Text(String)
+}
+
 /// A wrapper for `NSDraggingInfo`. As this is a protocol/type you should never create yourself,
 /// this only provides getters - merely a Rust-y way to grab what you need.
 #[derive(Clone, Debug)]
@@ -69,4 +86,42 @@ impl DragInfo {
             Pasteboard::with(msg_send![&*self.info, draggingPasteboard])
         }
     }
+
+    /// Returns the items being dragged, in a Rust-friendly format. URLs (file or otherwise) are
+    /// preferred; if there are none, any plain text on the pasteboard is returned instead.
+    pub fn get_items(&self) -> Vec<DropItem> {
+        unsafe {
+            let pasteboard: id = msg_send![&*self.info, draggingPasteboard];
+
+            let class: id = msg_send![class!(NSURL), class];
+            let classes = NSArray::new(&[class]);
+            let urls: id = msg_send![pasteboard, readObjectsForClasses:classes options:nil];
+
+            if urls != nil {
+                let items: Vec<DropItem> = NSArray::retain(urls).map(|url| {
+                    let is_file: BOOL = msg_send![url, isFileURL];
+
+                    if to_bool(is_file) {
+                        let path = NSString::retain(msg_send![url, path]);
+                        return Some(DropItem::File(PathBuf::from(path.to_str())));
+                    }
+
+                    let absolute = NSString::retain(msg_send![url, absoluteString]);
+                    Url::parse(absolute.to_str()).ok().map(DropItem::Url)
+                }).into_iter().flatten().collect();
+
+                if !items.is_empty() {
+                    return items;
+                }
+            }
+
+            let ptype: NSString = PasteboardType::String.into();
+            let text: id = msg_send![pasteboard, stringForType:&*ptype];
+
+            match text {
+                text if text == nil => vec![],
+                text => vec![DropItem::Text(NSString::retain(text).to_str().to_string())]
+            }
+        }
+    }
 }
@@ -39,6 +39,9 @@ pub use dictionary::NSMutableDictionary;
 mod number;
 pub use number::NSNumber;
 
+mod operation_queue;
+pub use operation_queue::OperationQueue;
+
 mod string;
 pub use string::NSString;
 
@@ -0,0 +1,130 @@
+use std::sync::Mutex;
+
+use block::ConcreteBlock;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::foundation::{NSInteger, NSString};
+
+/// A wrapper around `NSOperationQueue`.
+///
+/// Grand Central Dispatch (see `utils::async_main_thread` and friends) covers most background
+/// work needs, but some system APIs (e.g, CloudKit) expect to be handed an `NSOperationQueue`. This
+/// also gives you an easy way to throttle how many jobs run at once.
+#[derive(Clone, Debug)]
+pub struct OperationQueue(pub ShareId<Object>);
+
+impl Default for OperationQueue {
+    fn default() -> Self {
+        OperationQueue::new()
+    }
+}
+
+impl OperationQueue {
+    /// Creates and returns a new `OperationQueue`. Operations added to this queue run on
+    /// background threads.
+    pub fn new() -> Self {
+        OperationQueue(unsafe {
+            ShareId::from_retained_ptr(msg_send![class!(NSOperationQueue), new])
+        })
+    }
+
+    /// Returns the queue associated with the main thread. Operations added here run serially, on
+    /// the main thread, as part of the run loop.
+    pub fn main() -> Self {
+        OperationQueue(unsafe {
+            ShareId::from_ptr(msg_send![class!(NSOperationQueue), mainQueue])
+        })
+    }
+
+    /// Sets a name for this queue, which shows up in the debugger and crash logs.
+    pub fn set_name(&self, name: &str) {
+        let name = NSString::new(name);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setName:&*name];
+        }
+    }
+
+    /// Queues up `operation` to be run. Since operations are executed on whatever thread the
+    /// queue sees fit, the closure must be `Send`.
+    ///
+    /// ```rust,no_run
+    /// use cacao::foundation::OperationQueue;
+    ///
+    /// let queue = OperationQueue::new();
+    /// queue.set_max_concurrent_operations(2);
+    ///
+    /// for i in 0..10 {
+    ///     queue.add(move || {
+    ///         println!("Processing {}", i);
+    ///     });
+    /// }
+    ///
+    /// queue.wait_until_all_finished();
+    /// ```
+    pub fn add<F>(&self, operation: F)
+    where
+        F: FnOnce() + Send + 'static
+    {
+        // Blocks are `Fn`, and might in theory be copied around by the runtime; the queue will
+        // only ever invoke this once, but we guard with an `Option` so that's enforced on our
+        // side too.
+        let operation = Mutex::new(Some(operation));
+
+        let block = ConcreteBlock::new(move || {
+            let operation = operation.lock().unwrap().take();
+
+            if let Some(operation) = operation {
+                operation();
+            }
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, addOperationWithBlock:&*block];
+        }
+    }
+
+    /// Sets the maximum number of operations that can run at the same time. Passing `1` makes
+    /// this a serial queue.
+    ///
+    /// By default, the system determines this based on current conditions; call
+    /// `reset_max_concurrent_operations()` to go back to that.
+    pub fn set_max_concurrent_operations(&self, count: usize) {
+        let count = count as NSInteger;
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setMaxConcurrentOperationCount:count];
+        }
+    }
+
+    /// Resets the maximum number of concurrent operations to the system default.
+    pub fn reset_max_concurrent_operations(&self) {
+        // NSOperationQueueDefaultMaxConcurrentOperationCount
+        let count: NSInteger = -1;
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setMaxConcurrentOperationCount:count];
+        }
+    }
+
+    /// Cancels all queued operations that have not yet started. Operations already running are
+    /// left to finish.
+    pub fn cancel_all_operations(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, cancelAllOperations];
+        }
+    }
+
+    /// Blocks the current thread until all queued and running operations have finished.
+    ///
+    /// Don't call this on the main queue from the main thread - it'll never return.
+    pub fn wait_until_all_finished(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, waitUntilAllOperationsAreFinished];
+        }
+    }
+}
@@ -2,9 +2,13 @@
 
 use core_graphics::geometry::{CGRect, CGPoint, CGSize};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// A struct that represents a box - top, left, width and height. You might use this for, say,
 /// setting the initial frame of a view.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Rect {
     /// Distance from the top, in points.
     pub top: f64,
@@ -55,3 +59,102 @@ impl From<CGRect> for Rect {
         }
     }
 }
+
+/// A struct that represents a point in a coordinate space - e.g, where to draw something.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Point {
+    /// The x coordinate, in points.
+    pub x: f64,
+
+    /// The y coordinate, in points.
+    pub y: f64
+}
+
+impl Point {
+    /// Returns a new `Point` initialized with the values specified.
+    pub fn new(x: f64, y: f64) -> Self {
+        Point { x, y }
+    }
+
+    /// Returns a zero'd out Point.
+    pub fn zero() -> Self {
+        Point { x: 0.0, y: 0.0 }
+    }
+}
+
+impl From<Point> for CGPoint {
+    fn from(point: Point) -> CGPoint {
+        CGPoint::new(point.x, point.y)
+    }
+}
+
+impl From<CGPoint> for Point {
+    fn from(point: CGPoint) -> Point {
+        Point {
+            x: point.x as f64,
+            y: point.y as f64
+        }
+    }
+}
+
+/// A struct that represents a size - width and height.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Size {
+    /// Width, in points.
+    pub width: f64,
+
+    /// Height, in points.
+    pub height: f64
+}
+
+impl Size {
+    /// Returns a new `Size` initialized with the values specified.
+    pub fn new(width: f64, height: f64) -> Self {
+        Size { width, height }
+    }
+
+    /// Returns a zero'd out Size.
+    pub fn zero() -> Self {
+        Size { width: 0.0, height: 0.0 }
+    }
+}
+
+impl From<Size> for CGSize {
+    fn from(size: Size) -> CGSize {
+        CGSize::new(size.width, size.height)
+    }
+}
+
+impl From<CGSize> for Size {
+    fn from(size: CGSize) -> Size {
+        Size {
+            width: size.width as f64,
+            height: size.height as f64
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::{Point, Rect, Size};
+
+    #[test]
+    fn test_serde_round_trip() {
+        let rect = Rect::new(1., 2., 3., 4.);
+        let json = serde_json::to_string(&rect).unwrap();
+        assert_eq!(json, r#"{"top":1.0,"left":2.0,"width":3.0,"height":4.0}"#);
+
+        let rect: Rect = serde_json::from_str(&json).unwrap();
+        assert_eq!((rect.top, rect.left, rect.width, rect.height), (1., 2., 3., 4.));
+
+        let point = Point::new(5., 6.);
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(serde_json::from_str::<Point>(&json).unwrap(), point);
+
+        let size = Size::new(7., 8.);
+        let json = serde_json::to_string(&size).unwrap();
+        assert_eq!(serde_json::from_str::<Size>(&json).unwrap(), size);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::foundation::NSUInteger;
+
+/// Controls how the border of a `GroupBox` is drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BorderType {
+    /// No border. The box still groups (and optionally titles) its content.
+    None,
+
+    /// A thin line border. This uses a custom box type under the hood, which is also what enables
+    /// `set_corner_radius()` and friends.
+    Line,
+
+    /// The standard system bezel - what you'd see grouping sections in System Preferences.
+    Bezel
+}
+
+/// Where the title of a `GroupBox` is drawn, relative to the border.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TitlePosition {
+    /// Don't display the title.
+    None,
+
+    /// Title sits above the top border.
+    AboveTop,
+
+    /// Title sits in the top border.
+    AtTop,
+
+    /// Title sits below the top border.
+    BelowTop,
+
+    /// Title sits above the bottom border.
+    AboveBottom,
+
+    /// Title sits in the bottom border.
+    AtBottom,
+
+    /// Title sits below the bottom border.
+    BelowBottom
+}
+
+impl From<TitlePosition> for NSUInteger {
+    fn from(position: TitlePosition) -> Self {
+        match position {
+            TitlePosition::None => 0,
+            TitlePosition::AboveTop => 1,
+            TitlePosition::AtTop => 2,
+            TitlePosition::BelowTop => 3,
+            TitlePosition::AboveBottom => 4,
+            TitlePosition::AtBottom => 5,
+            TitlePosition::BelowBottom => 6
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! A titled, bordered container for grouping related controls.
+//!
+//! This wraps `NSBox`, which is the idiomatic way to build out sections in something like a
+//! settings pane. Rather than fiddling with layers and borders yourself, you can do:
+//!
+//! ```rust,no_run
+//! use cacao::group_box::{GroupBox, BorderType};
+//! use cacao::view::View;
+//!
+//! let section = GroupBox::new("Appearance");
+//! section.set_border_type(BorderType::Line);
+//! section.set_corner_radius(8.);
+//!
+//! let content = View::new();
+//! section.set_content_view(&content);
+//! ```
+
+use core_graphics::base::CGFloat;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, NO, NSArray, NSString, NSUInteger};
+use crate::color::Color;
+use crate::layout::{Layout, LayoutConstraint, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::utils::properties::ObjcProperty;
+
+mod enums;
+pub use enums::{BorderType, TitlePosition};
+
+/// `NSBoxPrimary`.
+const BOX_PRIMARY: NSUInteger = 0;
+
+/// `NSBoxCustom`.
+const BOX_CUSTOM: NSUInteger = 4;
+
+/// A wrapper for `NSBox`: a container that draws a border (and optionally a title) around its
+/// content.
+#[derive(Debug)]
+pub struct GroupBox {
+    /// A pointer to the Objective-C Object.
+    pub objc: ObjcProperty,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime left layout constraint.
+    pub left: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime right layout constraint.
+    pub right: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for GroupBox {
+    fn default() -> Self {
+        GroupBox::new("")
+    }
+}
+
+impl GroupBox {
+    /// Returns a new `GroupBox` with the given title, using the standard system bezel. Pass an
+    /// empty string (or set the title position to `TitlePosition::None`) for an untitled box.
+    pub fn new(title: &str) -> Self {
+        let title = NSString::new(title);
+
+        let view: id = unsafe {
+            let view: id = msg_send![class!(NSBox), new];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+            let _: () = msg_send![view, setTitle:&*title];
+            view
+        };
+
+        GroupBox {
+            top: LayoutAnchorY::top(view),
+            left: LayoutAnchorX::left(view),
+            leading: LayoutAnchorX::leading(view),
+            right: LayoutAnchorX::right(view),
+            trailing: LayoutAnchorX::trailing(view),
+            bottom: LayoutAnchorY::bottom(view),
+            width: LayoutAnchorDimension::width(view),
+            height: LayoutAnchorDimension::height(view),
+            center_x: LayoutAnchorX::center(view),
+            center_y: LayoutAnchorY::center(view),
+            objc: ObjcProperty::retain(view),
+        }
+    }
+
+    /// Sets the title for this box.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTitle:&*title];
+        });
+    }
+
+    /// Sets where the title is drawn, relative to the border.
+    pub fn set_title_position(&self, position: TitlePosition) {
+        let position: NSUInteger = position.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTitlePosition:position];
+        });
+    }
+
+    /// Sets the style of border for this box.
+    ///
+    /// `BorderType::Line` and `BorderType::None` switch the box over to a custom box type, which
+    /// is what allows the corner radius, border color and fill color to be configured.
+    pub fn set_border_type(&self, border_type: BorderType) {
+        self.objc.with_mut(|obj| unsafe {
+            match border_type {
+                BorderType::Bezel => {
+                    let _: () = msg_send![obj, setBoxType:BOX_PRIMARY];
+                },
+
+                BorderType::Line => {
+                    let _: () = msg_send![obj, setBoxType:BOX_CUSTOM];
+                    let _: () = msg_send![obj, setBorderWidth:1. as CGFloat];
+                },
+
+                BorderType::None => {
+                    let _: () = msg_send![obj, setBoxType:BOX_CUSTOM];
+                    let _: () = msg_send![obj, setBorderWidth:0. as CGFloat];
+                }
+            }
+        });
+    }
+
+    /// Sets the corner radius of the border. This only has an effect when the border type is
+    /// `BorderType::Line` or `BorderType::None`.
+    pub fn set_corner_radius(&self, radius: f64) {
+        let radius = radius as CGFloat;
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setCornerRadius:radius];
+        });
+    }
+
+    /// Sets the border color. This only has an effect when the border type is `BorderType::Line`.
+    pub fn set_border_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setBorderColor:color];
+        });
+    }
+
+    /// Sets the color used to fill the box. This only has an effect when the border type is
+    /// `BorderType::Line` or `BorderType::None`.
+    pub fn set_fill_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setFillColor:color];
+        });
+    }
+
+    /// Sets the distance between the border and the content, in points.
+    pub fn set_content_margins(&self, horizontal: f64, vertical: f64) {
+        let margins = crate::utils::CGSize::new(horizontal as CGFloat, vertical as CGFloat);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setContentViewMargins:margins];
+        });
+    }
+
+    /// Embeds `view` as the content of this box, replacing any view previously set here.
+    ///
+    /// `NSBox` manages the frame of its own content view (accounting for the title, border and
+    /// margins), so rather than swapping it out, we add `view` to it and pin all four edges with
+    /// AutoLayout. Your view can then use its own anchors as usual.
+    pub fn set_content_view<V: Layout>(&self, view: &V) {
+        self.objc.get(|obj| {
+            let content: id = unsafe { msg_send![obj, contentView] };
+
+            // Removing the old view also removes the constraints pinning it.
+            NSArray::retain(unsafe { msg_send![content, subviews] }).map(|subview| unsafe {
+                let _: () = msg_send![subview, removeFromSuperview];
+            });
+
+            view.with_backing_node(|subview| unsafe {
+                let _: () = msg_send![content, addSubview:subview];
+
+                LayoutConstraint::activate(&[
+                    LayoutAnchorY::top(subview).constraint_equal_to(&LayoutAnchorY::top(content)),
+                    LayoutAnchorX::leading(subview).constraint_equal_to(&LayoutAnchorX::leading(content)),
+                    LayoutAnchorX::trailing(subview).constraint_equal_to(&LayoutAnchorX::trailing(content)),
+                    LayoutAnchorY::bottom(subview).constraint_equal_to(&LayoutAnchorY::bottom(content))
+                ]);
+            });
+        });
+    }
+}
+
+impl Drop for GroupBox {
+    /// Removes the box from any superview, matching the semantics of `View` - if the Rust side
+    /// drops, the box shouldn't stick around in the hierarchy.
+    fn drop(&mut self) {
+        self.remove_from_superview();
+    }
+}
+
+impl Layout for GroupBox {
+    fn with_backing_node<F: Fn(id)>(&self, handler: F) {
+        self.objc.with_mut(handler);
+    }
+
+    fn get_from_backing_node<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+        self.objc.get(handler)
+    }
+}
@@ -11,7 +11,8 @@ use core_graphics::{
 };
 use core_graphics::context::{CGContext, CGContextRef};
 
-use crate::foundation::{id, YES, NO, NSString};
+use crate::foundation::{id, nil, YES, NO, NSString, NSUInteger};
+use crate::geometry::Point;
 use crate::utils::os;
 use super::icons::*;
 
@@ -118,6 +119,10 @@ pub struct DrawConfig {
     pub resize: ResizeBehavior
 }
 
+/// `NSCompositingOperationSourceOver`, which is what we want for layering images atop one another.
+#[cfg(target_os = "macos")]
+const SOURCE_OVER: NSUInteger = 2;
+
 /// Wraps `NSImage` on macOS, and `UIImage` on iOS and tvOS. Can be used to display images, icons,
 /// and so on.
 #[derive(Clone, Debug)]
@@ -222,4 +227,84 @@ impl Image {
             ShareId::from_ptr(img)
         })
     }
+
+    /// Returns the size of this image, in points.
+    #[cfg(target_os = "macos")]
+    pub fn size(&self) -> (CGFloat, CGFloat) {
+        let size: CGSize = unsafe { msg_send![&*self.0, size] };
+        (size.width, size.height)
+    }
+
+    /// Returns a new `Image`, sized to this one, with `other` drawn on top of it at the specified
+    /// point. The point is measured from the top-left corner of this image, and `other` is drawn
+    /// at its own size; anything that falls outside the bounds of this image is clipped.
+    ///
+    /// This is useful for composing icons, e.g a file type glyph atop a document icon.
+    #[cfg(target_os = "macos")]
+    pub fn overlaying(&self, other: &Image, at: Point) -> Image {
+        let (width, height) = other.size();
+        let frame = CGRect::new(&at.into(), &CGSize::new(width, height));
+        self.composite(other, frame)
+    }
+
+    /// Returns a new `Image` with `badge` drawn in the top-right corner, as you'd see for unread
+    /// counts on a Dock icon.
+    ///
+    /// If the badge is larger than half of this image's shortest side, it's scaled down (keeping
+    /// its aspect ratio) to fit - otherwise it's drawn at its own size.
+    #[cfg(target_os = "macos")]
+    pub fn badged(&self, badge: &Image) -> Image {
+        let (width, height) = self.size();
+        let (badge_width, badge_height) = badge.size();
+
+        let max_side = min_cgfloat(width, height) / 2.;
+        let scale = min_cgfloat(1., min_cgfloat(max_side / badge_width, max_side / badge_height));
+        let size = CGSize::new(badge_width * scale, badge_height * scale);
+
+        let frame = CGRect::new(&CGPoint::new(width - size.width, 0.), &size);
+        self.composite(badge, frame)
+    }
+
+    /// Handles the actual drawing for `overlaying()` and `badged()`. The returned image is backed
+    /// by a drawing handler, so it's redrawn (at the proper resolution) whenever it's rendered.
+    #[cfg(target_os = "macos")]
+    fn composite(&self, other: &Image, frame: CGRect) -> Image {
+        let (width, height) = self.size();
+        let size = CGSize::new(width, height);
+
+        let base = self.0.clone();
+        let overlay = other.0.clone();
+
+        let block = ConcreteBlock::new(move |destination: CGRect| unsafe {
+            let zero = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(0., 0.));
+
+            let _: () = msg_send![&*base, drawInRect:destination
+                fromRect:zero
+                operation:SOURCE_OVER
+                fraction:1.0 as CGFloat
+                respectFlipped:YES
+                hints:nil
+            ];
+
+            let _: () = msg_send![&*overlay, drawInRect:frame
+                fromRect:zero
+                operation:SOURCE_OVER
+                fraction:1.0 as CGFloat
+                respectFlipped:YES
+                hints:nil
+            ];
+
+            YES
+        });
+        let block = block.copy();
+
+        Image(unsafe {
+            let img: id = msg_send![class!(NSImage), imageWithSize:size
+                flipped:YES
+                drawingHandler:block
+            ];
+
+            ShareId::from_ptr(img)
+        })
+    }
 }
@@ -243,12 +243,14 @@ impl<T> TextField<T> {
         });
     }
 
-    /// Sets the font for this input.
+    /// Sets the font for this input, and invalidates its intrinsic content size so that layout
+    /// accounts for the new font.
     pub fn set_font<F: AsRef<Font>>(&self, font: F) {
         let font = font.as_ref().clone();
 
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setFont:&*font];
+            let _: () = msg_send![obj, invalidateIntrinsicContentSize];
         });
     }
 }
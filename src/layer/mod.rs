@@ -14,7 +14,7 @@ use core_graphics::base::CGFloat;
 
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::id;
+use crate::foundation::{id, YES, NO};
 use crate::utils::properties::ObjcProperty;
 
 /// Represents a `CALayer`.
@@ -59,4 +59,26 @@ impl Layer {
             let _: () = msg_send![obj, setCornerRadius:radius as CGFloat];
         });
     }
+
+    /// Sets whether this layer (and its sublayers) should be rendered into a bitmap, which is
+    /// then used for compositing instead of redrawing the subtree each frame.
+    ///
+    /// See `View::set_rasterized()` for the tradeoffs involved.
+    pub fn set_should_rasterize(&self, rasterize: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setShouldRasterize:match rasterize {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Sets the scale at which this layer is rasterized, if rasterization is enabled. This should
+    /// generally match the backing scale factor of the screen it's shown on (e.g, `2.0` for
+    /// Retina displays) - anything lower will look blurry.
+    pub fn set_rasterization_scale(&self, scale: f64) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setRasterizationScale:scale as CGFloat];
+        });
+    }
 }
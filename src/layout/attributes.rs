@@ -217,3 +217,39 @@ pub enum LayoutPriority {
     /// Low priority.
     Low
 }
+
+/// Represents the direction that a user interface is laid out in. Leading and trailing anchors
+/// follow this: in a right-to-left layout, `leading` is on the right.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum UserInterfaceLayoutDirection {
+    /// Left to right, e.g for English.
+    LeftToRight,
+
+    /// Right to left, e.g for Arabic or Hebrew.
+    RightToLeft,
+
+    /// Represents an unknown value. This should never be constructed, but acts as a guard against
+    /// a change in representation on the framework side. If a new value was ever introduced, it's
+    /// caught here, and applications can handle it themselves if need be.
+    Unknown(NSInteger)
+}
+
+impl From<NSInteger> for UserInterfaceLayoutDirection {
+    fn from(i: NSInteger) -> Self {
+        match i {
+            0 => Self::LeftToRight,
+            1 => Self::RightToLeft,
+            i => Self::Unknown(i)
+        }
+    }
+}
+
+impl From<UserInterfaceLayoutDirection> for NSInteger {
+    fn from(direction: UserInterfaceLayoutDirection) -> Self {
+        match direction {
+            UserInterfaceLayoutDirection::LeftToRight => 0,
+            UserInterfaceLayoutDirection::RightToLeft => 1,
+            UserInterfaceLayoutDirection::Unknown(i) => i
+        }
+    }
+}
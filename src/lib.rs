@@ -78,6 +78,9 @@
 //! exist. This feature is very uncommon and you probably don't need it.
 //! - `quicklook`: Links `QuickLook.framework` and offers methods for generating preview images for
 //! files.
+//! - `serde`: Enables reading and writing your own `Serialize`/`Deserialize` types to the
+//! `Pasteboard` and as `NotificationCenter` payloads, encoded as JSON, and implements
+//! `Serialize`/`Deserialize` for the `geometry` types.
 //! - `user-notifications`: Links `UserNotifications.framework` and provides functionality for
 //! emitting notifications on macOS and iOS. Note that this _requires_ your application be
 //! code-signed, and will not work without it.
@@ -129,6 +132,9 @@ pub mod filesystem;
 pub mod foundation;
 pub mod geometry;
 
+#[cfg(target_os = "macos")]
+pub mod group_box;
+
 #[cfg(target_os = "macos")]
 pub mod image;
 
@@ -41,7 +41,13 @@ extern fn view_for_column<T: ListViewDelegate>(
     item: NSInteger
 ) -> id {
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    let item = view.item_for(item as usize);
+    let row = item as usize;
+    let item = view.item_for(row);
+
+    // This runs for every row that's about to be shown, whether it was freshly created or
+    // dequeued for reuse, so the delegate can update it for the data at this index.
+    let reused = item.mark_configured();
+    view.configure_row(row, &item.clone_as_handle(), reused);
 
     // A hacky method of returning the underlying pointer
     // without Rust annoying us.
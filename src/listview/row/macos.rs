@@ -16,7 +16,7 @@ use objc_id::Id;
 
 use crate::foundation::{id, nil, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
-use crate::listview::row::{LISTVIEW_ROW_DELEGATE_PTR, BACKGROUND_COLOR, ViewDelegate};
+use crate::listview::row::{LISTVIEW_ROW_DELEGATE_PTR, LISTVIEW_ROW_CONFIGURED, BACKGROUND_COLOR, ViewDelegate};
 use crate::utils::load;
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
@@ -113,6 +113,8 @@ pub(crate) fn register_listview_row_class() -> *const Class {
         let superclass = class!(NSView);
         let mut decl = ClassDecl::new("RSTTableViewRow", superclass).unwrap();
 
+        decl.add_ivar::<BOOL>(LISTVIEW_ROW_CONFIGURED);
+
         decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
     
         VIEW_CLASS = decl.register();
@@ -135,6 +137,7 @@ pub(crate) fn register_listview_row_class_with_delegate<T: ViewDelegate>() -> *c
         // move.
         decl.add_ivar::<usize>(LISTVIEW_ROW_DELEGATE_PTR);
         decl.add_ivar::<id>(BACKGROUND_COLOR);
+        decl.add_ivar::<BOOL>(LISTVIEW_ROW_CONFIGURED);
         
         decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
         decl.add_method(sel!(updateLayer), update_layer as extern fn(&Object, _));
@@ -48,7 +48,7 @@ use objc_id::{Id, ShareId};
 use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
+use crate::foundation::{id, nil, to_bool, BOOL, YES, NO, NSArray, NSString};
 use crate::color::Color;
 use crate::layer::Layer;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
@@ -69,6 +69,7 @@ use ios::{register_listview_row_view_class, register_listview_row_class_with_del
 
 pub(crate) static BACKGROUND_COLOR: &str = "alchemyBackgroundColor";
 pub(crate) static LISTVIEW_ROW_DELEGATE_PTR: &str = "rstListViewRowDelegatePtr";
+pub(crate) static LISTVIEW_ROW_CONFIGURED: &str = "rstListViewRowConfigured";
 
 /// A helper method for instantiating view classes and applying default settings to them.
 fn allocate_view(registration_fn: fn() -> *const Class) -> id { 
@@ -257,11 +258,19 @@ impl<T> ListViewRow<T> {
     /// callback pointer. We use this in calling `did_load()` - implementing delegates get a way to
     /// reference, customize and use the view but without the trickery of holding pieces of the
     /// delegate - the `View` is the only true holder of those.
+    ///
+    /// The handle's `layer` is the row's actual backing layer (retained for the life of the
+    /// handle), so layer changes made through it apply to the row.
     pub(crate) fn clone_as_handle(&self) -> crate::view::View {
+        let layer = self.objc.get(|obj| unsafe {
+            let layer: id = msg_send![obj, layer];
+            Layer { objc: ObjcProperty::retain(layer) }
+        });
+
         crate::view::View {
             delegate: None,
             is_handle: true,
-            layer: Layer::new(),
+            layer,
             top: self.top.clone(),
             leading: self.leading.clone(),
             left: self.left.clone(),
@@ -276,6 +285,21 @@ impl<T> ListViewRow<T> {
         }
     }
 
+    /// Flags this row as having been passed to `ListViewDelegate::configure_row()`, returning
+    /// whether it already had been - i.e, whether it's being reused.
+    #[cfg(target_os = "macos")]
+    pub(crate) fn mark_configured(&self) -> bool {
+        let configured: BOOL = self.objc.get(|obj| unsafe {
+            *obj.get_ivar(LISTVIEW_ROW_CONFIGURED)
+        });
+
+        self.objc.with_mut(|obj| unsafe {
+            (&mut *obj).set_ivar::<BOOL>(LISTVIEW_ROW_CONFIGURED, YES);
+        });
+
+        to_bool(configured)
+    }
+
     /// Sets the identifier, which enables cells to be reused and dequeued properly.
     pub fn set_identifier(&self, identifier: &'static str) {
         let identifier = NSString::new(identifier);
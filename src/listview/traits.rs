@@ -36,6 +36,15 @@ pub trait ListViewDelegate {
     /// had time to sit down and figure them out properly yet.
     fn item_for(&self, row: usize) -> ListViewRow;
 
+    /// Called every time a row is about to be displayed, after `item_for()`. The `view` is a
+    /// handle to the row itself, so you can add controls (buttons, images, multiple labels, and
+    /// so on) to it.
+    ///
+    /// `reused` is `true` if this row has been displayed before (i.e, it was dequeued for reuse).
+    /// A reused row still holds whatever was added to it the first time around, so only add
+    /// subviews when `reused` is `false`; otherwise, just update what's already there.
+    fn configure_row(&self, row: usize, view: &View, reused: bool) {}
+
     /// Called when an item has been selected (clicked/tapped on).
     fn item_selected(&self, row: usize) {}
 
@@ -0,0 +1,228 @@
+//! Implements crash reporting for `App::set_crash_reporter()`.
+//!
+//! There are two ways an application can go down unexpectedly: a Rust panic, or an Objective-C
+//! exception that nothing catches. Both are funneled into a `CrashReport` here, handed to the
+//! user-supplied handler, and then surfaced as an `NSAlert` (on the main thread) before the
+//! process exits.
+
+use std::backtrace::Backtrace;
+use std::fmt;
+use std::panic;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+
+use lazy_static::lazy_static;
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, to_bool, BOOL, YES, NSArray, NSInteger, NSString};
+use crate::pasteboard::Pasteboard;
+
+/// The type of crash reporting callback we store.
+type CrashHandler = Arc<dyn Fn(&CrashReport) + Send + Sync + 'static>;
+
+lazy_static! {
+    static ref HANDLER: Mutex<Option<CrashHandler>> = Mutex::new(None);
+}
+
+/// Set once we've started reporting a crash, so that a second failure (e.g, another thread
+/// panicking while the alert is up) doesn't stack another alert on top.
+static REPORTING: AtomicBool = AtomicBool::new(false);
+
+/// `NSAlertFirstButtonReturn`; the second button is this plus one.
+const FIRST_BUTTON: NSInteger = 1000;
+
+extern "C" {
+    fn NSSetUncaughtExceptionHandler(handler: Option<extern "C" fn(id)>);
+}
+
+/// Where a crash originated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CrashSource {
+    /// A Rust panic.
+    Panic,
+
+    /// An uncaught Objective-C exception.
+    Exception
+}
+
+/// Describes an unexpected failure, as passed to the handler given to
+/// `App::set_crash_reporter()`. The `Display` implementation renders the full report, suitable
+/// for logging or sending along to a bug tracker.
+#[derive(Clone, Debug)]
+pub struct CrashReport {
+    /// Whether this came from a panic or an exception.
+    pub source: CrashSource,
+
+    /// For exceptions, the exception name (e.g, `NSInvalidArgumentException`). For panics, this
+    /// is always `panic`.
+    pub name: String,
+
+    /// The panic message or exception reason.
+    pub reason: String,
+
+    /// For panics, the source location that panicked, if known.
+    pub location: Option<String>,
+
+    /// The name of the thread the failure occurred on, if it has one.
+    pub thread: Option<String>,
+
+    /// A symbolicated backtrace, captured at the point of failure.
+    pub backtrace: String
+}
+
+impl fmt::Display for CrashReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.name, self.reason)?;
+
+        if let Some(location) = &self.location {
+            writeln!(f, "Location: {}", location)?;
+        }
+
+        writeln!(f, "Thread: {}", self.thread.as_deref().unwrap_or("<unnamed>"))?;
+        writeln!(f)?;
+        write!(f, "{}", self.backtrace)
+    }
+}
+
+/// Stores `handler` to be called when a crash is reported. The first call also installs the
+/// panic hook and uncaught exception handler; later calls just swap the handler out.
+pub(crate) fn install<F>(handler: F)
+where
+    F: Fn(&CrashReport) + Send + Sync + 'static
+{
+    *HANDLER.lock().unwrap() = Some(Arc::new(handler));
+
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(install_hooks);
+}
+
+/// Installs the panic hook and uncaught exception handler, both of which route through `report`.
+fn install_hooks() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        // Keep the default behavior (printing to stderr) so the panic still shows up in logs.
+        previous(info);
+
+        let reason = match info.payload().downcast_ref::<&str>() {
+            Some(s) => s.to_string(),
+            None => match info.payload().downcast_ref::<String>() {
+                Some(s) => s.clone(),
+                None => "Box<dyn Any>".to_string()
+            }
+        };
+
+        report(CrashReport {
+            source: CrashSource::Panic,
+            name: "panic".to_string(),
+            reason,
+            location: info.location().map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+            thread: thread::current().name().map(String::from),
+            backtrace: Backtrace::force_capture().to_string()
+        });
+    }));
+
+    unsafe {
+        // By default, AppKit catches exceptions thrown on the main thread and just logs them,
+        // leaving the app in whatever state it was in. This makes them crash (and thus reach our
+        // handler) instead.
+        let key = NSString::new("NSApplicationCrashOnExceptions");
+        let yes: id = msg_send![class!(NSNumber), numberWithBool:YES];
+        let defaults: id = msg_send![class!(NSDictionary), dictionaryWithObject:yes forKey:&*key];
+        let user_defaults: id = msg_send![class!(NSUserDefaults), standardUserDefaults];
+        let _: () = msg_send![user_defaults, registerDefaults:defaults];
+
+        NSSetUncaughtExceptionHandler(Some(handle_exception));
+    }
+}
+
+/// Called by Foundation when an exception goes uncaught.
+extern "C" fn handle_exception(exception: id) {
+    let (name, reason, backtrace) = unsafe {
+        let name = NSString::retain(msg_send![exception, name]).to_str().to_string();
+
+        let reason: id = msg_send![exception, reason];
+        let reason = match reason {
+            reason if reason == nil => String::new(),
+            reason => NSString::retain(reason).to_str().to_string()
+        };
+
+        let symbols: id = msg_send![exception, callStackSymbols];
+        let backtrace = NSArray::retain(symbols).map(|symbol| {
+            NSString::retain(symbol).to_str().to_string()
+        }).join("\n");
+
+        (name, reason, backtrace)
+    };
+
+    report(CrashReport {
+        source: CrashSource::Exception,
+        name,
+        reason,
+        location: None,
+        thread: thread::current().name().map(String::from),
+        backtrace
+    });
+}
+
+/// Hands the report to the user's handler, shows the alert, and exits. If we're not on the main
+/// thread, this blocks until the alert has been dealt with over there.
+fn report(report: CrashReport) {
+    let is_main_thread: BOOL = unsafe { msg_send![class!(NSThread), isMainThread] };
+    let is_main_thread = to_bool(is_main_thread);
+
+    // If another failure is already being reported, a background thread just waits for the
+    // process to exit - returning could otherwise abort before the first alert is dismissed.
+    if REPORTING.swap(true, Ordering::SeqCst) {
+        if !is_main_thread {
+            loop {
+                thread::park();
+            }
+        }
+
+        return;
+    }
+
+    // Clone the handler out so a panic inside it can't leave the lock poisoned for anyone else.
+    let handler = match HANDLER.lock() {
+        Ok(handler) => handler.clone(),
+        Err(poisoned) => poisoned.into_inner().clone()
+    };
+
+    if let Some(handler) = handler {
+        handler(&report);
+    }
+
+    match is_main_thread {
+        true => show_alert(&report),
+
+        false => dispatch::Queue::main().exec_sync(move || show_alert(&report))
+    }
+
+    std::process::exit(101);
+}
+
+/// Runs a modal alert describing the crash, offering to copy the full report to the clipboard.
+fn show_alert(report: &CrashReport) {
+    let title = NSString::new("The application encountered an unexpected error and needs to quit.");
+    let message = NSString::new(&report.reason);
+    let quit = NSString::new("Quit");
+    let copy = NSString::new("Copy Report and Quit");
+
+    let response: NSInteger = unsafe {
+        let alert: id = msg_send![class!(NSAlert), new];
+        let _: () = msg_send![alert, setMessageText:&*title];
+        let _: () = msg_send![alert, setInformativeText:&*message];
+        let _: id = msg_send![alert, addButtonWithTitle:&*quit];
+        let _: id = msg_send![alert, addButtonWithTitle:&*copy];
+        msg_send![alert, runModal]
+    };
+
+    if response == FIRST_BUTTON + 1 {
+        let pasteboard = Pasteboard::default();
+        pasteboard.clear_contents();
+        pasteboard.copy_text(report.to_string());
+    }
+}
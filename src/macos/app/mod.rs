@@ -39,11 +39,13 @@ use std::sync::{Arc, Mutex};
 
 use lazy_static::lazy_static;
 
+use url::Url;
+
 use objc_id::Id;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSUInteger, AutoReleasePool};
+use crate::foundation::{id, nil, YES, NO, NSArray, NSString, NSUInteger, AutoReleasePool};
 use crate::invoker::TargetActionHandler;
 use crate::macos::menu::Menu;
 use crate::notification_center::Dispatcher;
@@ -52,6 +54,9 @@ use crate::utils::activate_cocoa_multithreading;
 mod class;
 use class::register_app_class;
 
+mod crash;
+pub use crash::{CrashReport, CrashSource};
+
 mod delegate;
 use delegate::register_app_delegate_class;
 
@@ -253,6 +258,69 @@ impl App {
         });
     }
 
+    /// Adds a URL to the system's list of recent documents for this application. This is what
+    /// populates the "Open Recent" menu (see `MenuItem::OpenRecent`), and feeds the recent items
+    /// shown in the Dock and Apple menu.
+    ///
+    /// When a user picks an entry from that menu, it'll be delivered to your
+    /// `AppDelegate::open_urls()` implementation.
+    pub fn note_recent_document(url: &Url) {
+        let url = NSString::new(url.as_str());
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), URLWithString:&*url];
+            let controller: id = msg_send![class!(NSDocumentController), sharedDocumentController];
+            let _: () = msg_send![controller, noteNewRecentDocumentURL:url];
+        }
+    }
+
+    /// Returns the list of recent documents for this application, most recent first.
+    pub fn recent_documents() -> Vec<Url> {
+        unsafe {
+            let controller: id = msg_send![class!(NSDocumentController), sharedDocumentController];
+            let urls: id = msg_send![controller, recentDocumentURLs];
+
+            NSArray::retain(urls).map(|url| {
+                let uri = NSString::retain(msg_send![url, absoluteString]);
+                Url::parse(uri.to_str())
+            }).into_iter().filter_map(|url| url.ok()).collect()
+        }
+    }
+
+    /// Clears the list of recent documents for this application.
+    pub fn clear_recent_documents() {
+        unsafe {
+            let controller: id = msg_send![class!(NSDocumentController), sharedDocumentController];
+            let _: () = msg_send![controller, clearRecentDocuments:nil];
+        }
+    }
+
+    /// Installs a crash reporter for the application. Once set, both Rust panics (on any thread)
+    /// and uncaught Objective-C exceptions are routed through `handler` - e.g, to write the
+    /// report to disk or send it along somewhere - after which the user is shown an alert
+    /// explaining what happened, with the option to copy the full report to the clipboard. The
+    /// application then exits.
+    ///
+    /// Note that this means _any_ panic is treated as fatal, even one that would otherwise be
+    /// caught (e.g, by joining a thread). The existing panic hook still runs, so panics continue
+    /// to be printed as normal.
+    ///
+    /// This also registers `NSApplicationCrashOnExceptions` in the user defaults, which changes
+    /// behavior for the whole process: exceptions thrown on the main thread, which AppKit would
+    /// otherwise log and carry on from, now terminate the application (via this handler).
+    ///
+    /// Calling this again replaces the handler; the hooks themselves are only installed once.
+    ///
+    /// If the failure happens on a background thread, that thread blocks while the alert is shown
+    /// on the main thread; if the main thread is itself stuck (waiting on that thread, say), the
+    /// alert can't be shown.
+    pub fn set_crash_reporter<F>(handler: F)
+    where
+        F: Fn(&CrashReport) + Send + Sync + 'static
+    {
+        crash::install(handler);
+    }
+
     /// Sets a set of `Menu`'s as the top level Menu for the current application. Note that behind
     /// the scenes, Cocoa/AppKit make a copy of the menu you pass in - so we don't retain it, and
     /// you shouldn't bother to either.
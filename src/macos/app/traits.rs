@@ -1,6 +1,8 @@
 //! Traits that an implementing application can conform to. These aim to wrap the general
 //! lifecycles across macOS/iOS/etc, while still conforming to a Rust-ish approach.
 
+use std::path::PathBuf;
+
 use url::Url;
 
 use crate::error::Error;
@@ -144,8 +146,27 @@ pub trait AppDelegate {
     /// file."
     ///
     /// Note that since we have this as the de-facto method of handling resource opens, the system
-    /// will _not_ call `application:openFile:` or `application:openFiles`.
-    fn open_urls(&self, _urls: Vec<Url>) { }
+    /// will _not_ call `application:openFile:` or `application:openFiles`. If you don't implement
+    /// this, the default implementation forwards any file URLs on to `open_files()`.
+    fn open_urls(&self, urls: Vec<Url>) {
+        let paths: Vec<PathBuf> = urls.into_iter()
+            .filter_map(|url| url.to_file_path().ok())
+            .collect();
+
+        if !paths.is_empty() {
+            self.open_files(paths);
+        }
+    }
+
+    /// Fired when the user asks to open files with your application - e.g, by dropping them on
+    /// your Dock icon, or double clicking a document type you've registered in your `Info.plist`.
+    /// If your application isn't running, it'll be launched first.
+    ///
+    /// This isn't backed by a delegate method of its own: since we implement
+    /// `application:openURLs:`, AppKit never sends `application:openFile:` or
+    /// `application:openFiles:`. Instead, the default `open_urls()` implementation calls through
+    /// to here with any file URLs it receives - so if you override `open_urls()`, this won't fire.
+    fn open_files(&self, _paths: Vec<PathBuf>) { }
 
     /// Fired when the file is requested to be opened programmatically. This is not a commonly used
     /// or implemented method.
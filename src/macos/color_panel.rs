@@ -0,0 +1,123 @@
+//! A wrapper for the shared `NSColorPanel`, which lets users pick a color with the standard system
+//! picker.
+//!
+//! There's only ever one color panel per application, so `ColorPanel` is just a handle to it -
+//! you can grab one wherever you need it. The same goes for the change callback: setting one
+//! replaces whatever was set before.
+//!
+//! ```rust,no_run
+//! use cacao::macos::ColorPanel;
+//!
+//! let panel = ColorPanel::shared();
+//! panel.set_action(|color| {
+//!     println!("User picked: {:?}", color);
+//! });
+//! panel.show();
+//! ```
+
+use std::sync::{Arc, Mutex, RwLock};
+
+use lazy_static::lazy_static;
+
+use objc_id::{Id, ShareId};
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::color::Color;
+use crate::foundation::{id, nil, YES, NO};
+use crate::invoker::TargetActionHandler;
+
+lazy_static! {
+    /// The panel is a singleton, and so is its target - we hold the handler here rather than on
+    /// any one `ColorPanel` handle so that dropping a handle doesn't pull the target out from
+    /// under the panel.
+    static ref HANDLER: Mutex<Option<TargetActionHandler>> = Mutex::new(None);
+}
+
+/// A handle to the application's shared `NSColorPanel`.
+#[derive(Clone, Debug)]
+pub struct ColorPanel(pub ShareId<Object>);
+
+impl ColorPanel {
+    /// Returns a handle to the shared color panel, creating it if need be.
+    pub fn shared() -> Self {
+        ColorPanel(unsafe {
+            ShareId::from_ptr(msg_send![class!(NSColorPanel), sharedColorPanel])
+        })
+    }
+
+    /// Shows the color panel, bringing it to the front.
+    pub fn show(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, orderFront:nil];
+        }
+    }
+
+    /// Hides the color panel.
+    pub fn hide(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, orderOut:nil];
+        }
+    }
+
+    /// Sets the color currently selected in the panel. Note that this will fire the change
+    /// callback, if one is set.
+    pub fn set_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setColor:color];
+        }
+    }
+
+    /// Returns the color currently selected in the panel.
+    pub fn color(&self) -> Color {
+        Color::Custom(Arc::new(RwLock::new(unsafe {
+            Id::from_ptr(msg_send![&*self.0, color])
+        })))
+    }
+
+    /// Sets whether the panel lets the user pick an opacity, too.
+    pub fn set_shows_alpha(&self, shows_alpha: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setShowsAlpha:match shows_alpha {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the change callback fires continuously as the user drags around the picker,
+    /// or only once they've settled on a color. This is continuous by default.
+    pub fn set_continuous(&self, continuous: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setContinuous:match continuous {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets a callback to fire whenever the selected color changes, replacing any previous one.
+    /// The callback is handed the newly selected color, and fires on the main thread.
+    pub fn set_action<F: Fn(Color) + Send + Sync + 'static>(&self, action: F) {
+        let panel = self.clone();
+        let handler = TargetActionHandler::new(&*self.0, move || {
+            action(panel.color());
+        });
+
+        let mut current = HANDLER.lock().unwrap();
+        *current = Some(handler);
+    }
+
+    /// Removes the change callback, if one is set.
+    pub fn clear_action(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setTarget:nil];
+            let _: () = msg_send![&*self.0, setAction:nil];
+        }
+
+        let mut current = HANDLER.lock().unwrap();
+        *current = None;
+    }
+}
@@ -110,6 +110,10 @@ pub enum MenuItem {
     /// Close the current window.
     CloseWindow,
 
+    /// A standard "Open Recent" submenu. AppKit fills this in automatically from the list of
+    /// recent documents (see `App::note_recent_document()`), and includes a "Clear Menu" item.
+    OpenRecent,
+
     /// A "quit this app" menu icon.
     Quit,
 
@@ -183,6 +187,21 @@ impl MenuItem {
                 Some(&[EventModifierFlag::Command, EventModifierFlag::Option])
             ),
 
+            // AppKit looks for a menu containing an item targeting `clearRecentDocuments:`, and
+            // manages the recent items in that menu from then on.
+            Self::OpenRecent => {
+                let item = make_menu_item("Open Recent", None, None, None);
+                let clear = make_menu_item("Clear Menu", None, Some(sel!(clearRecentDocuments:)), None);
+
+                let title = NSString::new("Open Recent");
+                let alloc: id = msg_send![class!(NSMenu), alloc];
+                let submenu: id = msg_send![alloc, initWithTitle:&*title];
+                let _: () = msg_send![submenu, addItem:&*clear];
+                let _: () = msg_send![&*item, setSubmenu:submenu];
+                let _: () = msg_send![submenu, release];
+                item
+            },
+
             Self::ShowAll => make_menu_item("Show All", None, Some(sel!(unhideAllApplications:)), None),
             Self::CloseWindow => make_menu_item("Close Window", Some("w"), Some(sel!(performClose:)), None),
             Self::Quit => make_menu_item("Quit", Some("q"), Some(sel!(terminate:)), None),
@@ -11,6 +11,9 @@ pub use alert::Alert;
 mod app;
 pub use app::*;
 
+mod color_panel;
+pub use color_panel::ColorPanel;
+
 mod cursor;
 pub use cursor::{Cursor, CursorType};
 
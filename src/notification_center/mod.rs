@@ -11,14 +11,37 @@
 //! integrating with certain aspects of the underlying Cocoa/Foundation/Kit frameworks.
 //!
 //! ## Example
+//! ```rust,no_run
+//! use cacao::notification_center::NotificationCenter;
+//!
+//! let center = NotificationCenter::default();
+//!
+//! // Hold on to this for as long as you want to keep observing.
+//! let observer = center.observe("com.my.app.did-sync", || {
+//!     println!("Synced!");
+//! });
+//!
+//! center.post("com.my.app.did-sync");
+//! ```
+//!
+//! With the `serde` feature enabled, notifications can also carry a typed payload - see
+//! `NotificationCenter::post_payload()` and `NotificationCenter::observe_payload()`.
+
+use block::ConcreteBlock;
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSString};
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
 
-//use std::sync::Mutex;
-//use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use crate::foundation::{NSData, NSMutableDictionary};
 
-//use lazy_static::lazy_static;
-//use objc::{class, msg_send, sel, sel_impl};
-//use objc::runtime::Object;
-//use objc_id::ShareId;
+#[cfg(feature = "serde")]
+use crate::error::Error;
 
 mod name;
 pub use name::NotificationName;
@@ -26,48 +49,228 @@ pub use name::NotificationName;
 mod traits;
 pub use traits::Dispatcher;
 
-/*lazy_static! {
-    pub static ref DefaultNotificationCenter: NotificationCenter = {
-        NotificationCenter {
-            objc: unsafe {
-                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
-            },
+/// The `userInfo` key that typed payloads are stored under.
+#[cfg(feature = "serde")]
+pub(crate) static PAYLOAD_KEY: &str = "com.cacao-rs.notification-payload";
 
-            subscribers: Mutex::new(HashMap::new())
-        }
-    };
-}*/
-
-// Wraps a reference to an `NSNotificationCenter` instance. Currently this only supports the
-// default center; in the future it should aim to support custom variants.
-//#[derive(Debug)]
-//pub struct NotificationCenter {
-//    pub objc: ShareId<Object>,
-    //pub subscribers: Mutex<HashMap<String, Vec<Dispatcher>>>
-//}
+/// Wraps a reference to an `NSNotificationCenter` instance. Currently this only supports the
+/// default center; in the future it should aim to support custom variants.
+#[derive(Clone, Debug)]
+pub struct NotificationCenter(pub ShareId<Object>);
 
-/*impl Default for NotificationCenter {
+impl Default for NotificationCenter {
     /// Returns a wrapper over `[NSNotificationCenter defaultCenter]`. From here you can handle
-    /// observing, removing, and posting notifications.
+    /// observing and posting notifications.
     fn default() -> Self {
-        NotificationCenter {
-            objc: unsafe {
-                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
+        NotificationCenter(unsafe {
+            ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
+        })
+    }
+}
+
+impl NotificationCenter {
+    /// Posts a notification with the given name. Observers are called synchronously, on the
+    /// calling thread, before this returns.
+    pub fn post(&self, name: &str) {
+        let name = NSString::new(name);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, postNotificationName:&*name object:nil];
+        }
+    }
+
+    /// Calls `handler` whenever a notification with the given name is posted - whether by your own
+    /// code or the system. The handler runs on whichever thread posted the notification, which
+    /// is why it must be `Send + Sync`; hop over to the main thread before touching any UI.
+    ///
+    /// Observation lasts for as long as the returned `NotificationObserver` is alive.
+    pub fn observe<F>(&self, name: &str, handler: F) -> NotificationObserver
+    where
+        F: Fn() + Send + Sync + 'static
+    {
+        self.add_observer(name, move |_notification| handler())
+    }
+
+    /// Posts a notification carrying `payload`, which is serialized (as JSON) into the
+    /// notification's `userInfo`. Observers registered with `observe_payload()` receive it
+    /// decoded; plain `observe()` observers are still notified, they just don't see it.
+    ///
+    /// ```rust,no_run
+    /// use cacao::notification_center::NotificationCenter;
+    ///
+    /// let center = NotificationCenter::default();
+    ///
+    /// let observer = center.observe_payload("com.my.app.progress", |progress: Result<(u32, u32), _>| {
+    ///     if let Ok((done, total)) = progress {
+    ///         println!("{} of {}", done, total);
+    ///     }
+    /// });
+    ///
+    /// center.post_payload("com.my.app.progress", &(3, 10)).unwrap();
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn post_payload<T: Serialize>(&self, name: &str, payload: &T) -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = serde_json::to_vec(payload)?;
+        let data = NSData::new(bytes);
+        let name = NSString::new(name);
+
+        let mut user_info = NSMutableDictionary::new();
+        user_info.insert(NSString::new(PAYLOAD_KEY), &*data as *const Object as id);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, postNotificationName:&*name object:nil userInfo:&*user_info];
+        }
+
+        Ok(())
+    }
+
+    /// Calls `handler` with the decoded payload whenever a notification with the given name is
+    /// posted via `post_payload()`. As with `observe()`, the handler runs on whichever thread
+    /// posted the notification.
+    ///
+    /// Notifications carry no type information, so it's up to you to agree on the payload type
+    /// for a given name. If a notification arrives without a payload (e.g, it was sent with
+    /// `post()`, or by the system), or the payload doesn't decode to a `T`, the handler receives
+    /// an `Err` describing what went wrong rather than the notification being silently dropped.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn observe_payload<T, F>(&self, name: &str, handler: F) -> NotificationObserver
+    where
+        T: DeserializeOwned,
+        F: Fn(Result<T, Box<dyn std::error::Error>>) + Send + Sync + 'static
+    {
+        let notification_name = name.to_string();
+
+        self.add_observer(name, move |notification| {
+            handler(decode_payload(&notification_name, notification));
+        })
+    }
+
+    /// Registers a block-based observer for the given name, and wraps the returned token so it
+    /// can be removed on drop.
+    fn add_observer<F>(&self, name: &str, handler: F) -> NotificationObserver
+    where
+        F: Fn(id) + Send + Sync + 'static
+    {
+        let name = NSString::new(name);
+
+        let block = ConcreteBlock::new(move |notification: id| {
+            handler(notification);
+        });
+        let block = block.copy();
+
+        NotificationObserver {
+            center: self.0.clone(),
+            token: unsafe {
+                ShareId::from_ptr(msg_send![&*self.0, addObserverForName:&*name
+                    object:nil
+                    queue:nil
+                    usingBlock:&*block
+                ])
             }
         }
     }
-}*/
+}
 
-/*impl NotificationCenter {
-    pub fn observe<T: Dispatcher>(&self, name: &str, handler: &T) {
+/// Pulls the payload out of a notification's `userInfo` and decodes it.
+#[cfg(feature = "serde")]
+fn decode_payload<T: DeserializeOwned>(name: &str, notification: id) -> Result<T, Box<dyn std::error::Error>> {
+    let key = NSString::new(PAYLOAD_KEY);
 
+    let data: id = unsafe {
+        let user_info: id = msg_send![notification, userInfo];
+
+        match user_info {
+            user_info if user_info == nil => nil,
+            user_info => msg_send![user_info, objectForKey:&*key]
+        }
+    };
+
+    if data == nil || !NSData::is(data) {
+        return Err(Box::new(Error {
+            code: 669,
+            domain: "com.cacao-rs.notification-center".to_string(),
+            description: format!("Notification {} has no payload.", name)
+        }));
     }
 
-    pub fn remove<T: Dispatcher>(&self, name: &str, handler: &T) {
+    let data = NSData::retain(data);
+    Ok(serde_json::from_slice(data.bytes())?)
+}
+
+/// An active observation, as returned from `NotificationCenter::observe()` and friends. The
+/// handler is removed from the notification center when this is dropped.
+#[derive(Debug)]
+pub struct NotificationObserver {
+    center: ShareId<Object>,
+    token: ShareId<Object>
+}
 
+impl Drop for NotificationObserver {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.center, removeObserver:&*self.token];
+        }
     }
+}
 
-    pub fn post(&self, name: &str) {
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::error::Error;
+
+    use super::{NotificationCenter, NotificationObserver};
+
+    type Received = Arc<Mutex<Vec<Result<(u32, u32), String>>>>;
+
+    /// Observes `name` for `(u32, u32)` payloads, returning what was delivered. Errors are
+    /// flattened to whether they were our own missing-payload error, or a decoding error.
+    fn observe(center: &NotificationCenter, name: &str) -> (NotificationObserver, Received) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let results = received.clone();
+
+        let observer = center.observe_payload(name, move |payload: Result<(u32, u32), _>| {
+            results.lock().unwrap().push(payload.map_err(|e| {
+                if let Some(e) = e.downcast_ref::<Error>() {
+                    return format!("missing: {}", e.code);
+                }
+
+                match e.downcast_ref::<serde_json::Error>() {
+                    Some(_) => "decode".to_string(),
+                    None => e.to_string()
+                }
+            }));
+        });
+
+        (observer, received)
+    }
+
+    #[test]
+    fn test_payload_round_trip() {
+        let center = NotificationCenter::default();
+        let (_observer, received) = observe(&center, "com.cacao-rs.test.round-trip");
+
+        center.post_payload("com.cacao-rs.test.round-trip", &(3u32, 10u32)).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![Ok((3, 10))]);
+    }
+
+    #[test]
+    fn test_missing_payload() {
+        let center = NotificationCenter::default();
+        let (_observer, received) = observe(&center, "com.cacao-rs.test.missing");
+
+        center.post("com.cacao-rs.test.missing");
+        assert_eq!(*received.lock().unwrap(), vec![Err("missing: 669".to_string())]);
+    }
+
+    #[test]
+    fn test_mismatched_payload() {
+        let center = NotificationCenter::default();
+        let (_observer, received) = observe(&center, "com.cacao-rs.test.mismatch");
 
+        center.post_payload("com.cacao-rs.test.mismatch", &"not a tuple").unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![Err("decode".to_string())]);
     }
-}*/
+}
@@ -20,7 +20,10 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 use url::Url;
 
-use crate::foundation::{id, nil, NSString, NSArray};
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::foundation::{id, nil, to_bool, BOOL, NSInteger, NSString, NSArray, NSData};
 use crate::error::Error;
 
 mod types;
@@ -73,6 +76,81 @@ impl Pasteboard {
         }
     }
 
+    /// Writes raw data to the pasteboard under the given type (typically a UTI, e.g
+    /// `com.my.app.item`). The type is added to the existing pasteboard declaration, so you can
+    /// write multiple representations for the same copy operation - call `clear_contents()` first
+    /// if you want to replace what's there.
+    ///
+    /// Returns `false` if the data could not be written.
+    pub fn set_data(&self, ty: &str, data: NSData) -> bool {
+        let ptype = NSString::new(ty);
+
+        unsafe {
+            let types: NSArray = vec![&*ptype].into();
+            let _: NSInteger = msg_send![&*self.0, addTypes:&*types owner:nil];
+            let result: BOOL = msg_send![&*self.0, setData:&*data forType:&*ptype];
+            to_bool(result)
+        }
+    }
+
+    /// Reads raw data from the pasteboard for the given type, if any exists.
+    pub fn data_for_type(&self, ty: &str) -> Option<NSData> {
+        let ptype = NSString::new(ty);
+
+        unsafe {
+            let data: id = msg_send![&*self.0, dataForType:&*ptype];
+
+            match data {
+                data if data == nil => None,
+                data => Some(NSData::retain(data))
+            }
+        }
+    }
+
+    /// Serializes `value` as JSON and writes it to the pasteboard under the given type. This is
+    /// useful for copying your own model types around within your application.
+    ///
+    /// ```rust,no_run
+    /// use cacao::pasteboard::Pasteboard;
+    ///
+    /// let pasteboard = Pasteboard::default();
+    /// pasteboard.clear_contents();
+    ///
+    /// let todos = vec!["Milk".to_string(), "Eggs".to_string()];
+    /// pasteboard.write_serde("com.my.app.todos", &todos).unwrap();
+    ///
+    /// let todos: Vec<String> = pasteboard.read_serde("com.my.app.todos").unwrap();
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn write_serde<T: Serialize>(&self, ty: &str, value: &T) -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = serde_json::to_vec(value)?;
+
+        match self.set_data(ty, NSData::new(bytes)) {
+            true => Ok(()),
+            false => Err(Box::new(Error {
+                code: 667,
+                domain: "com.cacao-rs.pasteboard".to_string(),
+                description: format!("Unable to write data for type {} to the pasteboard.", ty)
+            }))
+        }
+    }
+
+    /// Reads and deserializes a value previously written with `write_serde()`. This returns an
+    /// error if there's nothing on the pasteboard for the given type, or if the data found there
+    /// doesn't decode to a `T`.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn read_serde<T: DeserializeOwned>(&self, ty: &str) -> Result<T, Box<dyn std::error::Error>> {
+        let data = self.data_for_type(ty).ok_or_else(|| Box::new(Error {
+            code: 668,
+            domain: "com.cacao-rs.pasteboard".to_string(),
+            description: format!("Pasteboard has no data for type {}.", ty)
+        }))?;
+
+        Ok(serde_json::from_slice(data.bytes())?)
+    }
+
     /// Releases the receiver’s resources in the pasteboard server. It's rare-ish to need to use
     /// this, but considering this stuff happens on the Objective-C side you may need it.
     pub fn release_globally(&self) {
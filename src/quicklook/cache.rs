@@ -0,0 +1,361 @@
+//! A small cache on top of `ThumbnailGenerator`, for cases (e.g, a file browser grid) where the
+//! same thumbnails get requested over and over while scrolling.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use core_graphics::base::CGFloat;
+
+use crate::error::Error;
+use crate::image::Image;
+
+use super::{ThumbnailConfig, ThumbnailGenerator, ThumbnailQuality};
+
+/// Cached thumbnails are keyed on the file path, along with the pixel dimensions requested.
+type CacheKey = (PathBuf, usize, usize);
+
+/// A callback waiting on a value that's currently being generated.
+type Waiter<V> = Box<dyn Fn(Result<V, Error>) + Send + Sync + 'static>;
+
+/// A cached value, along with bookkeeping for eviction.
+#[derive(Debug)]
+struct Entry<V> {
+    value: V,
+    pixels: usize,
+    last_used: u64
+}
+
+/// The shared, lock-guarded state for a `ThumbnailCache`. This is generic over the stored value
+/// so that the eviction and coalescing logic can be exercised without QuickLook.
+struct CacheState<V> {
+    entries: HashMap<CacheKey, Entry<V>>,
+    in_flight: HashMap<CacheKey, Vec<Waiter<V>>>,
+    pixels: usize,
+    budget: usize,
+    tick: u64
+}
+
+impl<V: Clone> CacheState<V> {
+    /// Creates an empty cache that holds, at most, `budget` pixels.
+    fn new(budget: usize) -> Self {
+        CacheState {
+            entries: HashMap::new(),
+            in_flight: HashMap::new(),
+            pixels: 0,
+            budget,
+            tick: 0
+        }
+    }
+
+    /// Returns the cached value for `key` (if any), marking it as recently used.
+    fn get(&mut self, key: &CacheKey) -> Option<V> {
+        self.tick += 1;
+        let tick = self.tick;
+
+        self.entries.get_mut(key).map(|entry| {
+            entry.last_used = tick;
+            entry.value.clone()
+        })
+    }
+
+    /// Stores `value` under `key`, then evicts least-recently-used entries until the cache fits
+    /// within the budget. Values that are larger than the entire budget aren't stored at all.
+    fn insert(&mut self, key: CacheKey, value: V, pixels: usize) {
+        if pixels > self.budget {
+            return;
+        }
+
+        self.tick += 1;
+
+        if let Some(previous) = self.entries.insert(key, Entry { value, pixels, last_used: self.tick }) {
+            self.pixels -= previous.pixels;
+        }
+
+        self.pixels += pixels;
+
+        while self.pixels > self.budget {
+            let oldest = self.entries.iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+
+            match oldest.and_then(|key| self.entries.remove(&key)) {
+                Some(entry) => { self.pixels -= entry.pixels; },
+                None => { break; }
+            }
+        }
+    }
+
+    /// Queues `waiter` on `key`. Returns `true` if nothing was in flight for `key` already, in
+    /// which case the caller is responsible for starting generation.
+    fn wait(&mut self, key: CacheKey, waiter: Waiter<V>) -> bool {
+        match self.in_flight.get_mut(&key) {
+            Some(waiters) => {
+                waiters.push(waiter);
+                false
+            },
+
+            None => {
+                self.in_flight.insert(key, vec![waiter]);
+                true
+            }
+        }
+    }
+
+    /// Records a result from the generator for `key`, returning any waiters that should now be
+    /// called (along with what to call them with). Call these after releasing the lock.
+    ///
+    /// Successful values are cached, and the first one releases the waiters. Failures only
+    /// release them if `is_final` is set (i.e, no further representations can arrive), since a
+    /// later one may still succeed.
+    fn complete(
+        &mut self,
+        key: &CacheKey,
+        result: Result<V, Error>,
+        pixels: usize,
+        is_final: bool
+    ) -> Option<(Vec<Waiter<V>>, Result<V, Error>)> {
+        match result {
+            Ok(value) => {
+                self.insert(key.clone(), value.clone(), pixels);
+                self.in_flight.remove(key).map(|waiters| (waiters, Ok(value)))
+            },
+
+            Err(e) if is_final => self.in_flight.remove(key).map(|waiters| (waiters, Err(e))),
+
+            Err(_) => None
+        }
+    }
+
+    /// Removes all cached values. Requests that are in flight are unaffected.
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.pixels = 0;
+    }
+}
+
+/// An LRU cache of QuickLook thumbnails, bounded by the total number of pixels it holds.
+///
+/// Requests for a thumbnail that's already being generated are coalesced - the generator is only
+/// asked once, and every callback waiting on it fires when it completes.
+///
+/// This is cheap to clone; clones share the same underlying cache.
+#[derive(Clone)]
+pub struct ThumbnailCache {
+    generator: ThumbnailGenerator,
+    state: Arc<Mutex<CacheState<Image>>>,
+    scale: CGFloat
+}
+
+impl std::fmt::Debug for ThumbnailCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap();
+
+        f.debug_struct("ThumbnailCache")
+            .field("generator", &self.generator)
+            .field("entries", &state.entries.len())
+            .field("in_flight", &state.in_flight.len())
+            .field("pixels", &state.pixels)
+            .field("budget", &state.budget)
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+
+impl ThumbnailCache {
+    /// Creates a new cache that will hold, at most, `pixel_budget` pixels worth of thumbnails
+    /// (e.g, `256 * 256 * 100` for roughly a hundred 128pt thumbnails at 2x), generated at the
+    /// given `scale` factor (e.g, `2.` for Retina displays).
+    ///
+    /// The scale is fixed for the life of the cache (and every clone of it), so that the same
+    /// request always maps to the same cached thumbnail.
+    pub fn new(pixel_budget: usize, scale: CGFloat) -> Self {
+        ThumbnailCache {
+            generator: ThumbnailGenerator::shared(),
+            state: Arc::new(Mutex::new(CacheState::new(pixel_budget))),
+            scale
+        }
+    }
+
+    /// Fetches a thumbnail for the file at `path`, at the given size (in points).
+    ///
+    /// If the thumbnail is cached, `callback` is called immediately on the current thread.
+    /// Otherwise, it's called from a background thread once the thumbnail has been generated, so
+    /// be sure to hop back to the main thread before touching any UI.
+    pub fn thumbnail<F>(&self, path: &Path, size: (CGFloat, CGFloat), callback: F)
+    where
+        F: Fn(Result<Image, Error>) + Send + Sync + 'static
+    {
+        let width = (size.0 * self.scale).round() as usize;
+        let height = (size.1 * self.scale).round() as usize;
+        let key: CacheKey = (path.to_path_buf(), width, height);
+
+        {
+            let mut state = self.state.lock().unwrap();
+
+            if let Some(image) = state.get(&key) {
+                drop(state);
+                callback(Ok(image));
+                return;
+            }
+
+            // If this is already being generated, just wait in line.
+            if !state.wait(key.clone(), Box::new(callback)) {
+                return;
+            }
+        }
+
+        let config = ThumbnailConfig {
+            size,
+            scale: self.scale,
+            types: &[ThumbnailQuality::All],
+            ..ThumbnailConfig::default()
+        };
+
+        let state = self.state.clone();
+
+        // The generator reports each representation as it becomes available (icon, then low,
+        // then high quality). Waiters are handed the first one that arrives; later (better) ones
+        // replace what's in the cache. A failed representation only fails the waiters if it was
+        // the last one to come.
+        self.generator.generate_representations(path, config, move |result, is_final| {
+            let result = result.map(|(image, _quality)| image);
+            let completed = state.lock().unwrap().complete(&key, result, width * height, is_final);
+
+            if let Some((waiters, result)) = completed {
+                for waiter in waiters {
+                    waiter(result.clone());
+                }
+            }
+        });
+    }
+
+    /// Removes all cached thumbnails. Requests that are in flight are unaffected.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    use crate::error::Error;
+
+    use super::{CacheKey, CacheState, Waiter};
+
+    fn key(name: &str) -> CacheKey {
+        (PathBuf::from(name), 10, 10)
+    }
+
+    fn error() -> Error {
+        Error {
+            code: 0,
+            domain: "com.cacao-rs.test".to_string(),
+            description: "failed".to_string()
+        }
+    }
+
+    /// Returns a waiter that records what it was called with.
+    fn recorder() -> (Waiter<u32>, Arc<Mutex<Vec<Result<u32, String>>>>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+
+        let waiter: Waiter<u32> = Box::new(move |result| {
+            recorded.lock().unwrap().push(result.map_err(|e| e.description));
+        });
+
+        (waiter, calls)
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut state = CacheState::new(300);
+        state.insert(key("a"), 1, 100);
+        state.insert(key("b"), 2, 100);
+        state.insert(key("c"), 3, 100);
+
+        // Touch "a", so "b" is now the oldest.
+        assert_eq!(state.get(&key("a")), Some(1));
+
+        state.insert(key("d"), 4, 100);
+        assert_eq!(state.get(&key("b")), None);
+        assert_eq!(state.get(&key("a")), Some(1));
+        assert_eq!(state.get(&key("d")), Some(4));
+        assert_eq!(state.pixels, 300);
+    }
+
+    #[test]
+    fn test_skips_values_over_budget() {
+        let mut state = CacheState::new(100);
+        state.insert(key("a"), 1, 101);
+
+        assert_eq!(state.get(&key("a")), None);
+        assert_eq!(state.pixels, 0);
+    }
+
+    #[test]
+    fn test_replacing_updates_pixel_count() {
+        let mut state = CacheState::new(300);
+        state.insert(key("a"), 1, 100);
+        state.insert(key("a"), 2, 200);
+
+        assert_eq!(state.get(&key("a")), Some(2));
+        assert_eq!(state.pixels, 200);
+    }
+
+    #[test]
+    fn test_coalesces_waiters() {
+        let mut state = CacheState::new(300);
+        let (first, first_calls) = recorder();
+        let (second, second_calls) = recorder();
+
+        assert!(state.wait(key("a"), first));
+        assert!(!state.wait(key("a"), second));
+
+        let (waiters, result) = state.complete(&key("a"), Ok(7), 100, false).unwrap();
+        assert_eq!(waiters.len(), 2);
+
+        for waiter in waiters {
+            waiter(result.clone());
+        }
+
+        assert_eq!(*first_calls.lock().unwrap(), vec![Ok(7)]);
+        assert_eq!(*second_calls.lock().unwrap(), vec![Ok(7)]);
+
+        // Later representations only update the cache.
+        assert!(state.complete(&key("a"), Ok(8), 100, true).is_none());
+        assert_eq!(state.get(&key("a")), Some(8));
+    }
+
+    #[test]
+    fn test_failure_waits_for_final_representation() {
+        let mut state = CacheState::new(300);
+        let (waiter, _) = recorder();
+        state.wait(key("a"), waiter);
+
+        assert!(state.complete(&key("a"), Err(error()), 0, false).is_none());
+
+        let (waiters, result) = state.complete(&key("a"), Ok(7), 100, true).unwrap();
+        assert_eq!(waiters.len(), 1);
+        assert_eq!(result.ok(), Some(7));
+    }
+
+    #[test]
+    fn test_final_failure_releases_waiters() {
+        let mut state: CacheState<u32> = CacheState::new(300);
+        let (waiter, calls) = recorder();
+        state.wait(key("a"), waiter);
+
+        assert!(state.complete(&key("a"), Err(error()), 0, false).is_none());
+
+        let (waiters, result) = state.complete(&key("a"), Err(error()), 0, true).unwrap();
+        for waiter in waiters {
+            waiter(result.clone());
+        }
+
+        assert_eq!(*calls.lock().unwrap(), vec![Err("failed".to_string())]);
+        assert!(state.in_flight.is_empty());
+    }
+}
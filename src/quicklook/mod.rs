@@ -13,7 +13,14 @@ use crate::image::Image;
 mod config;
 pub use config::{ThumbnailConfig, ThumbnailQuality};
 
-#[derive(Debug)]
+mod cache;
+pub use cache::ThumbnailCache;
+
+/// `QLThumbnailRepresentationTypeThumbnail`, the best representation the generator produces. Once
+/// it's been reported (or has failed), no more representations arrive for that request.
+const REPRESENTATION_THUMBNAIL: NSUInteger = 2;
+
+#[derive(Clone, Debug)]
 pub struct ThumbnailGenerator(pub ShareId<Object>);
 
 impl ThumbnailGenerator {
@@ -26,17 +33,29 @@ impl ThumbnailGenerator {
     pub fn generate<F>(&self, path: &Path, config: ThumbnailConfig, callback: F)
     where
         F: Fn(Result<(Image, ThumbnailQuality), Error>) + Send + Sync + 'static
+    {
+        self.generate_representations(path, config, move |result, _is_final| callback(result));
+    }
+
+    /// Like `generate`, but also tells the callback whether this is the last representation the
+    /// generator will report - i.e, the full thumbnail (or the failure to produce one). This only
+    /// holds if the config requests `ThumbnailQuality::High` (or `All`).
+    pub(crate) fn generate_representations<F>(&self, path: &Path, config: ThumbnailConfig, callback: F)
+    where
+        F: Fn(Result<(Image, ThumbnailQuality), Error>, bool) + Send + Sync + 'static
     {
         let block = ConcreteBlock::new(move |thumbnail: id, thumbnail_type: NSUInteger, error: id| {
+            let is_final = thumbnail_type == REPRESENTATION_THUMBNAIL;
+
             if error == nil {
                 unsafe {
                     let image = Image::with(msg_send![thumbnail, NSImage]);
                     let quality = ThumbnailQuality::from(thumbnail_type);
-                    callback(Ok((image, quality)));
+                    callback(Ok((image, quality)), is_final);
                 }
             } else {
                 let error = Error::new(error);
-                callback(Err(error));
+                callback(Err(error), is_final);
             }
         });
 
@@ -1,3 +1,5 @@
+use core_graphics::base::CGFloat;
+
 use crate::foundation::{NSInteger, NSUInteger};
 
 /// Specifies how text should align for a supported control.
@@ -66,4 +68,170 @@ impl Into<NSUInteger> for LineBreakMode {
     }
 }
 
+/// Specifies the weight of a font - e.g, for `Font::system_with_weight()`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FontWeight {
+    /// The thinnest weight.
+    UltraLight,
+
+    /// Thin.
+    Thin,
+
+    /// Light.
+    Light,
+
+    /// The standard weight.
+    Regular,
+
+    /// Medium.
+    Medium,
+
+    /// Semibold.
+    Semibold,
+
+    /// Bold.
+    Bold,
+
+    /// Heavy.
+    Heavy,
+
+    /// The heaviest weight.
+    Black
+}
+
+extern "C" {
+    static NSFontWeightUltraLight: CGFloat;
+    static NSFontWeightThin: CGFloat;
+    static NSFontWeightLight: CGFloat;
+    static NSFontWeightRegular: CGFloat;
+    static NSFontWeightMedium: CGFloat;
+    static NSFontWeightSemibold: CGFloat;
+    static NSFontWeightBold: CGFloat;
+    static NSFontWeightHeavy: CGFloat;
+    static NSFontWeightBlack: CGFloat;
+}
+
+impl From<FontWeight> for CGFloat {
+    fn from(weight: FontWeight) -> Self {
+        unsafe {
+            match weight {
+                FontWeight::UltraLight => NSFontWeightUltraLight,
+                FontWeight::Thin => NSFontWeightThin,
+                FontWeight::Light => NSFontWeightLight,
+                FontWeight::Regular => NSFontWeightRegular,
+                FontWeight::Medium => NSFontWeightMedium,
+                FontWeight::Semibold => NSFontWeightSemibold,
+                FontWeight::Bold => NSFontWeightBold,
+                FontWeight::Heavy => NSFontWeightHeavy,
+                FontWeight::Black => NSFontWeightBlack
+            }
+        }
+    }
+}
+
+/// Symbolic traits that can be applied to a font via `Font::with_traits()`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FontTrait {
+    /// An italic variant.
+    Italic,
+
+    /// A bold variant.
+    Bold,
+
+    /// An expanded (wider) variant.
+    Expanded,
+
+    /// A condensed (narrower) variant.
+    Condensed,
+
+    /// A fixed-pitch variant.
+    Monospace
+}
+
+impl From<FontTrait> for u32 {
+    fn from(font_trait: FontTrait) -> Self {
+        match font_trait {
+            FontTrait::Italic => 1 << 0,
+            FontTrait::Bold => 1 << 1,
+            FontTrait::Expanded => 1 << 5,
+            FontTrait::Condensed => 1 << 6,
+            FontTrait::Monospace => 1 << 10
+        }
+    }
+}
+
+/// Semantic text styles, which map to the system's preferred fonts for various roles - e.g, for
+/// `Font::preferred()`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextStyle {
+    /// A large title.
+    LargeTitle,
+
+    /// A first-level title.
+    Title1,
+
+    /// A second-level title.
+    Title2,
+
+    /// A third-level title.
+    Title3,
+
+    /// A heading.
+    Headline,
+
+    /// A subheading.
+    Subheadline,
+
+    /// Body text.
+    Body,
+
+    /// Callouts.
+    Callout,
+
+    /// Footnotes.
+    Footnote,
 
+    /// Standard captions.
+    Caption1,
+
+    /// Alternate captions.
+    Caption2
+}
+
+impl TextStyle {
+    /// Returns the `NSFontTextStyle` string for this style. These are only exported from AppKit
+    /// on macOS 11.0+, so we use the raw values rather than linking the symbols.
+    pub(crate) fn to_str(&self) -> &'static str {
+        match self {
+            TextStyle::LargeTitle => "UICTFontTextStyleTitle0",
+            TextStyle::Title1 => "UICTFontTextStyleTitle1",
+            TextStyle::Title2 => "UICTFontTextStyleTitle2",
+            TextStyle::Title3 => "UICTFontTextStyleTitle3",
+            TextStyle::Headline => "UICTFontTextStyleHeadline",
+            TextStyle::Subheadline => "UICTFontTextStyleSubhead",
+            TextStyle::Body => "UICTFontTextStyleBody",
+            TextStyle::Callout => "UICTFontTextStyleCallout",
+            TextStyle::Footnote => "UICTFontTextStyleFootnote",
+            TextStyle::Caption1 => "UICTFontTextStyleCaption1",
+            TextStyle::Caption2 => "UICTFontTextStyleCaption2"
+        }
+    }
+
+    /// The point size (and whether it's bold) that macOS 11 uses for this style. Used as a
+    /// fallback on systems that don't support text styles.
+    pub(crate) fn fallback(&self) -> (CGFloat, bool) {
+        match self {
+            TextStyle::LargeTitle => (26., false),
+            TextStyle::Title1 => (22., false),
+            TextStyle::Title2 => (17., false),
+            TextStyle::Title3 => (15., false),
+            TextStyle::Headline => (13., true),
+            TextStyle::Subheadline => (11., false),
+            TextStyle::Body => (13., false),
+            TextStyle::Callout => (12., false),
+            TextStyle::Footnote => (10., false),
+            TextStyle::Caption1 => (10., false),
+            TextStyle::Caption2 => (10., false)
+        }
+    }
+}
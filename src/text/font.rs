@@ -4,11 +4,13 @@ use std::ops::Deref;
 
 use core_graphics::base::CGFloat;
 
-use objc_id::ShareId;
+use objc_id::{Id, ShareId};
 use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
+use crate::foundation::{id, nil, YES, NO, NSArray, NSMutableDictionary, NSString};
+use crate::text::{FontTrait, FontWeight, TextStyle};
+use crate::utils::os;
 
 /// A `Font` can be constructed and applied to supported controls to control things like text
 /// appearance and size.
@@ -20,7 +22,7 @@ impl Default for Font {
     fn default() -> Self {
         Font(unsafe {
             let cls = class!(NSFont);
-            let default_size: id = msg_send![cls, labelFontSize];
+            let default_size: CGFloat = msg_send![cls, labelFontSize];
             ShareId::from_ptr(msg_send![cls, labelFontOfSize:default_size])
         })
     }
@@ -37,13 +39,167 @@ impl Font {
     }
 
     /// Creates and returns a default bold system font at the specified size.
-    pub fn bold_system(size: f64) -> Self {
+    pub fn system_bold(size: f64) -> Self {
         let size = size as CGFloat;
 
         Font(unsafe {
             ShareId::from_ptr(msg_send![class!(NSFont), boldSystemFontOfSize:size])
         })
     }
+
+    /// Creates and returns a default bold system font at the specified size.
+    #[deprecated(note = "Use `Font::system_bold` instead")]
+    pub fn bold_system(size: f64) -> Self {
+        Font::system_bold(size)
+    }
+
+    /// Creates and returns a system font at the specified size and weight.
+    pub fn system_with_weight(size: f64, weight: FontWeight) -> Self {
+        let size = size as CGFloat;
+        let weight: CGFloat = weight.into();
+
+        Font(unsafe {
+            ShareId::from_ptr(msg_send![class!(NSFont), systemFontOfSize:size weight:weight])
+        })
+    }
+
+    /// Creates and returns a fixed-width system font at the specified size (e.g, for code or
+    /// tabular numbers).
+    ///
+    /// The dedicated monospaced system font is only available on macOS 10.15+; on older systems,
+    /// this falls back to the user's fixed-pitch font.
+    pub fn monospaced(size: f64) -> Self {
+        let size = size as CGFloat;
+
+        Font(unsafe {
+            ShareId::from_ptr(match os::is_minimum_semversion(10, 15, 0) {
+                true => {
+                    let weight: CGFloat = FontWeight::Regular.into();
+                    msg_send![class!(NSFont), monospacedSystemFontOfSize:size weight:weight]
+                },
+
+                false => msg_send![class!(NSFont), userFixedPitchFontOfSize:size]
+            })
+        })
+    }
+
+    /// Looks up a font by its name (e.g, `"Menlo-Regular"` or `"Helvetica Neue"`), returning
+    /// `None` if no such font is installed.
+    pub fn named(name: &str, size: f64) -> Option<Self> {
+        let name = NSString::new(name);
+        let size = size as CGFloat;
+
+        unsafe {
+            let font: id = msg_send![class!(NSFont), fontWithName:&*name size:size];
+
+            match font {
+                font if font == nil => None,
+                font => Some(Font(ShareId::from_ptr(font)))
+            }
+        }
+    }
+
+    /// Returns the font the system prefers for the given text style (e.g, `Body`, `Headline`),
+    /// which keeps text in your app visually consistent with the rest of the platform.
+    ///
+    /// Text styles are supported on macOS 11.0+; on older systems, this returns a system font
+    /// sized to match what the style would resolve to on 11.0.
+    pub fn preferred(style: TextStyle) -> Self {
+        if os::is_minimum_version(11) {
+            let style = NSString::new(style.to_str());
+            let options = NSMutableDictionary::new();
+
+            return Font(unsafe {
+                ShareId::from_ptr(msg_send![class!(NSFont), preferredFontForTextStyle:&*style
+                    options:&*options
+                ])
+            });
+        }
+
+        match style.fallback() {
+            (size, true) => Font::system_bold(size),
+            (size, false) => Font::system(size)
+        }
+    }
+
+    /// Returns a variant of this font with the given traits (e.g, italic, condensed) applied, at
+    /// the same size. Traits are resolved through the font descriptor, so this works for any font
+    /// family that has the matching faces.
+    ///
+    /// If the font family has no face matching the requested traits, this returns a copy of the
+    /// original font rather than failing.
+    pub fn with_traits(&self, traits: &[FontTrait]) -> Self {
+        let traits = traits.iter().fold(0, |mask, t| {
+            let value: u32 = (*t).into();
+            mask | value
+        });
+
+        unsafe {
+            let descriptor: id = msg_send![&*self.0, fontDescriptor];
+            let existing: u32 = msg_send![descriptor, symbolicTraits];
+            let descriptor: id = msg_send![descriptor, fontDescriptorWithSymbolicTraits:(existing | traits)];
+            let size = self.point_size() as CGFloat;
+
+            let font: id = msg_send![class!(NSFont), fontWithDescriptor:descriptor size:size];
+
+            match font {
+                font if font == nil => self.clone(),
+                font => Font(ShareId::from_ptr(font))
+            }
+        }
+    }
+
+    /// Returns a variant of this font at the given weight, in the same family and at the same
+    /// size. Any other traits the font has (e.g, italic) are kept.
+    pub fn with_weight(&self, weight: FontWeight) -> Self {
+        let weight: CGFloat = weight.into();
+        let traits_key = NSString::new("NSCTFontTraitsAttribute");
+
+        unsafe {
+            let descriptor: id = msg_send![&*self.0, fontDescriptor];
+
+            // The traits attribute is replaced wholesale when we add ours below, so start from a
+            // copy of the existing one to avoid dropping the symbolic traits.
+            let font_attributes: id = msg_send![descriptor, fontAttributes];
+            let existing: id = msg_send![font_attributes, objectForKey:&*traits_key];
+            let mut traits = match existing {
+                existing if existing == nil => NSMutableDictionary::new(),
+                existing => NSMutableDictionary(Id::from_retained_ptr(msg_send![existing, mutableCopy]))
+            };
+
+            let weight: id = msg_send![class!(NSNumber), numberWithDouble:weight];
+            traits.insert(NSString::new("NSCTFontWeightTrait"), weight);
+
+            let mut attributes = NSMutableDictionary::new();
+            attributes.insert(traits_key, &mut *traits);
+
+            let descriptor: id = msg_send![descriptor, fontDescriptorByAddingAttributes:&*attributes];
+            let size = self.point_size() as CGFloat;
+
+            let font: id = msg_send![class!(NSFont), fontWithDescriptor:descriptor size:size];
+
+            match font {
+                font if font == nil => self.clone(),
+                font => Font(ShareId::from_ptr(font))
+            }
+        }
+    }
+
+    /// Returns a copy of this font at a different size.
+    pub fn with_size(&self, size: f64) -> Self {
+        let size = size as CGFloat;
+
+        Font(unsafe {
+            let descriptor: id = msg_send![&*self.0, fontDescriptor];
+            ShareId::from_ptr(msg_send![class!(NSFont), fontWithDescriptor:descriptor size:size])
+        })
+    }
+
+    /// Returns the point size of this font.
+    pub fn point_size(&self) -> f64 {
+        let size: CGFloat = unsafe { msg_send![&*self.0, pointSize] };
+        size as f64
+    }
 }
 
 impl Deref for Font {
@@ -301,7 +301,8 @@ impl<T> Label<T> {
         });
     }
 
-    /// Sets the font for this label.
+    /// Sets the font for this label. The label's intrinsic content size is invalidated, so any
+    /// layout constraints pick up the new text metrics.
     pub fn set_font<F: AsRef<Font>>(&self, font: F) {
         // This clone is here to ensure there's no oddities with retain counts on the underlying
         // font object - it seems like it can be optimized away otherwise.
@@ -309,6 +310,7 @@ impl<T> Label<T> {
 
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setFont:&*font];
+            let _: () = msg_send![obj, invalidateIntrinsicContentSize];
         });
     }
 
@@ -8,7 +8,7 @@ mod label;
 pub use label::Label;
 
 mod enums;
-pub use enums::{LineBreakMode, TextAlign};
+pub use enums::{FontTrait, FontWeight, LineBreakMode, TextAlign, TextStyle};
 
 mod font;
 pub use font::Font;
@@ -0,0 +1,88 @@
+//! Implements an `NSObject` subclass that acts as the `UNUserNotificationCenterDelegate`,
+//! forwarding calls over to a Rust-side `NotificationDelegate`.
+
+use std::ffi::c_void;
+use std::sync::Once;
+
+use block::Block;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+
+use crate::foundation::{id, NSUInteger};
+use crate::user_notifications::notifications::Notification;
+use crate::user_notifications::traits::NotificationDelegate;
+use crate::utils::load;
+
+pub(crate) static NOTIFICATION_DELEGATE_PTR: &str = "rstNotificationDelegatePtr";
+
+/// Called when a `userNotificationCenter:willPresentNotification:withCompletionHandler:` message
+/// is received. The completion handler must always be invoked, or the system will consider the
+/// notification to be in limbo.
+extern fn will_present_notification<T: NotificationDelegate>(
+    this: &Object,
+    _: Sel,
+    _center: id,
+    notification: id,
+    handler: id
+) {
+    let delegate = load::<T>(this, NOTIFICATION_DELEGATE_PTR);
+
+    let content = Notification::with_inner(unsafe {
+        let request: id = msg_send![notification, request];
+        msg_send![request, content]
+    });
+
+    let mut options: NSUInteger = 0;
+    for option in delegate.will_present(content) {
+        options = options | NSUInteger::from(option);
+    }
+
+    unsafe {
+        let handler = handler as *const Block<(NSUInteger,), c_void>;
+        (*handler).call((options,));
+    }
+}
+
+/// Drops the Rust-side delegate when the Objective-C side goes away.
+extern fn dealloc<T: NotificationDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(NOTIFICATION_DELEGATE_PTR);
+        let obj = ptr as *mut T;
+
+        if !obj.is_null() {
+            let _delegate = Box::from_raw(obj);
+        }
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers an `NSObject` subclass that implements `UNUserNotificationCenterDelegate`. Much like
+/// the `AppDelegate`, this is registered once - you should only have one `NotificationDelegate`
+/// type in your application.
+pub(crate) fn register_notification_delegate_class<T: NotificationDelegate>() -> *const Class {
+    static mut DELEGATE_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        let mut decl = ClassDecl::new("RSTNotificationCenterDelegate", superclass).unwrap();
+
+        decl.add_ivar::<usize>(NOTIFICATION_DELEGATE_PTR);
+
+        decl.add_method(
+            sel!(userNotificationCenter:willPresentNotification:withCompletionHandler:),
+            will_present_notification::<T> as extern fn(&Object, _, id, id, id)
+        );
+
+        decl.add_method(sel!(dealloc), dealloc::<T> as extern fn(&Object, _));
+
+        DELEGATE_CLASS = decl.register();
+    });
+
+    unsafe {
+        DELEGATE_CLASS
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::foundation::NSUInteger;
 
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum NotificationAuthOption {
     Badge,
     Sound,
@@ -27,3 +28,44 @@ impl From<&NotificationAuthOption> for NSUInteger {
         }
     }
 }
+
+/// Options that control how a notification is presented while your application is in the
+/// foreground. By default, macOS will not show anything for a notification delivered while your
+/// app is frontmost - return some combination of these from
+/// `NotificationDelegate::will_present` to change that.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NotificationPresentationOption {
+    /// Apply the notification's badge value to the app's icon.
+    Badge,
+
+    /// Play the sound associated with the notification.
+    Sound,
+
+    /// Display the alert using the content provided by the notification. Deprecated as of macOS
+    /// 11 in favor of `Banner` and `List`, but required for anything older.
+    Alert,
+
+    /// Show the notification in Notification Center. macOS 11+.
+    List,
+
+    /// Present the notification as a banner. macOS 11+.
+    Banner
+}
+
+impl From<NotificationPresentationOption> for NSUInteger {
+    fn from(option: NotificationPresentationOption) -> Self {
+        match option {
+            NotificationPresentationOption::Badge => 1 << 0,
+            NotificationPresentationOption::Sound => 1 << 1,
+            NotificationPresentationOption::Alert => 1 << 2,
+            NotificationPresentationOption::List => 1 << 3,
+            NotificationPresentationOption::Banner => 1 << 4
+        }
+    }
+}
+
+impl From<&NotificationPresentationOption> for NSUInteger {
+    fn from(option: &NotificationPresentationOption) -> Self {
+        NSUInteger::from(*option)
+    }
+}
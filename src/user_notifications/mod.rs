@@ -9,15 +9,22 @@ use block::ConcreteBlock;
 use objc::{class, msg_send, sel, sel_impl};
 use uuid::Uuid;
 
-use crate::foundation::{id, nil, NSString, NSUInteger};
+use crate::foundation::{id, nil, to_bool, BOOL, NSString, NSUInteger};
 
 pub mod enums;
-pub use enums::NotificationAuthOption;
+pub use enums::{NotificationAuthOption, NotificationPresentationOption};
+
+mod delegate;
+use delegate::{register_notification_delegate_class, NOTIFICATION_DELEGATE_PTR};
 
 pub mod notifications;
 pub use notifications::Notification;
 
+pub mod traits;
+pub use traits::NotificationDelegate;
+
 /// Acts as a central interface to the Notification Center on macOS.
+#[derive(Debug)]
 pub struct NotificationCenter;
 
 impl NotificationCenter {
@@ -26,7 +33,11 @@ impl NotificationCenter {
         unsafe {
             // @TODO: Revisit.
             let block = ConcreteBlock::new(|_: id, error: id| {
-                let localized_description = NSString::wrap(msg_send![error, localizedDescription]); 
+                if error == nil {
+                    return;
+                }
+
+                let localized_description = NSString::retain(msg_send![error, localizedDescription]);
                 let e = localized_description.to_str();
                 if e != "" {
                     println!("{:?}", e);
@@ -36,7 +47,7 @@ impl NotificationCenter {
             let mut opts: NSUInteger = 0;
             for opt in options {
                 let o: NSUInteger = opt.into();
-                opts = opts << o;
+                opts = opts | o;
             }
 
             let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
@@ -44,6 +55,34 @@ impl NotificationCenter {
         }
     }
 
+    /// Sets the delegate for the notification center, which receives callbacks for things like
+    /// notifications arriving while your application is in the foreground.
+    ///
+    /// The notification center only holds a weak reference to its delegate, so this retains it
+    /// for you; calling this again will release (and drop) any delegate previously set here.
+    pub fn set_delegate<T: NotificationDelegate + 'static>(delegate: T) {
+        let delegate = Box::new(delegate);
+
+        unsafe {
+            let delegate_class = register_notification_delegate_class::<T>();
+            let objc_delegate: id = msg_send![delegate_class, new];
+            let ptr = Box::into_raw(delegate);
+            (&mut *objc_delegate).set_ivar(NOTIFICATION_DELEGATE_PTR, ptr as usize);
+
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let existing: id = msg_send![center, delegate];
+            let _: () = msg_send![center, setDelegate:objc_delegate];
+
+            if existing != nil {
+                let is_ours: BOOL = msg_send![existing, isKindOfClass:delegate_class];
+
+                if to_bool(is_ours) {
+                    let _: () = msg_send![existing, release];
+                }
+            }
+        }
+    }
+
     /// Queues up a `Notification` to be displayed to the user.
     pub fn notify(notification: Notification) {
         let uuidentifier = format!("{}", Uuid::new_v4());
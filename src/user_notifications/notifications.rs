@@ -9,6 +9,7 @@ use crate::foundation::{id, NSString};
 
 /// A wrapper for `UNMutableNotificationContent`. Retains the pointer from the Objective C side,
 /// and is ultimately dropped upon sending.
+#[derive(Debug)]
 pub struct Notification(pub Id<Object>);
 
 impl Notification {
@@ -25,4 +26,26 @@ impl Notification {
             Id::from_ptr(content)
         })
     }
+
+    /// Wraps and retains an existing `UNNotificationContent`, e.g one vended to us by the system
+    /// in a delegate callback.
+    pub(crate) fn with_inner(content: id) -> Self {
+        Notification(unsafe {
+            Id::from_ptr(content)
+        })
+    }
+
+    /// Returns the title of this notification.
+    pub fn title(&self) -> String {
+        NSString::retain(unsafe {
+            msg_send![&*self.0, title]
+        }).to_string()
+    }
+
+    /// Returns the body of this notification.
+    pub fn body(&self) -> String {
+        NSString::retain(unsafe {
+            msg_send![&*self.0, body]
+        }).to_string()
+    }
 }
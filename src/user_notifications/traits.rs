@@ -0,0 +1,19 @@
+//! Traits for hooking into `UNUserNotificationCenter` callbacks.
+
+use crate::user_notifications::enums::NotificationPresentationOption;
+use crate::user_notifications::notifications::Notification;
+
+/// Implement this trait and pass it to `NotificationCenter::set_delegate` to receive callbacks
+/// from the notification center. Note that Apple recommends setting this before your application
+/// finishes launching (e.g, in `AppDelegate::will_finish_launching`).
+pub trait NotificationDelegate {
+    /// Called when a notification is about to be delivered while your application is in the
+    /// foreground. Return the ways you'd like it to be presented; the default (an empty `Vec`)
+    /// matches the system behavior of silently delivering it.
+    ///
+    /// A chat application, for instance, might return `Banner` and `Sound` here so that incoming
+    /// messages are still surfaced while the app is frontmost.
+    fn will_present(&self, _notification: Notification) -> Vec<NotificationPresentationOption> {
+        Vec::new()
+    }
+}
@@ -12,6 +12,15 @@ use objc_id::ShareId;
 
 use crate::foundation::{id, BOOL, YES, NO};
 
+#[cfg(target_os = "macos")]
+use objc::runtime::Sel;
+
+#[cfg(target_os = "macos")]
+use crate::foundation::{nil, to_bool};
+
+#[cfg(target_os = "macos")]
+use crate::layout::Layout;
+
 mod cell_factory;
 pub use cell_factory::CellFactory;
 
@@ -67,6 +76,43 @@ where
     queue.exec_sync(method);    
 }
 
+/// Sends an action message up the responder chain, starting from the current first responder,
+/// on behalf of `from` (the sender). This is how standard commands (Copy, Undo, and so on) find
+/// whichever view or control should handle them - and it's what lets custom controls trigger
+/// them too.
+///
+/// Returns `true` if some responder handled the action.
+///
+/// ```rust,no_run
+/// use cacao::objc::{sel, sel_impl};
+/// use cacao::utils::send_action;
+/// use cacao::view::View;
+///
+/// let view = View::new();
+/// send_action(sel!(copy:), &view);
+/// ```
+#[cfg(target_os = "macos")]
+pub fn send_action<V: Layout>(action: Sel, from: &V) -> bool {
+    from.get_from_backing_node(|sender| {
+        to_bool(unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            msg_send![app, sendAction:action to:nil from:sender]
+        })
+    })
+}
+
+/// Returns whether anything in the responder chain (starting from the current first responder)
+/// will currently handle `action`. Useful for, say, deciding whether a custom control should be
+/// enabled.
+#[cfg(target_os = "macos")]
+pub fn can_send_action(action: Sel) -> bool {
+    unsafe {
+        let app: id = msg_send![class!(NSApplication), sharedApplication];
+        let target: id = msg_send![app, targetForAction:action to:nil from:nil];
+        target != nil
+    }
+}
+
 /// Upstream core graphics does not implement Encode for certain things, so we wrap them here -
 /// these are only used in reading certain types passed to us from some delegate methods.
 #[repr(C)]
@@ -9,6 +9,8 @@
 
 use std::sync::Once;
 
+use core_graphics::base::CGFloat;
+
 use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
@@ -16,7 +18,7 @@ use objc_id::Id;
 
 use crate::foundation::{load_or_register_class, id, nil, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
-use crate::view::{VIEW_DELEGATE_PTR, BACKGROUND_COLOR, ViewDelegate};
+use crate::view::{VIEW_DELEGATE_PTR, BACKGROUND_COLOR, RASTERIZATION_TRACKS_SCALE, ViewDelegate};
 use crate::utils::load;
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
@@ -87,6 +89,39 @@ extern fn update_layer(this: &Object, _: Sel) {
     }
 }
 
+/// Returns the backing scale factor for the screen this view is on - or, if it's not in a window
+/// yet, for the main screen.
+pub(crate) fn backing_scale_factor(view: &Object) -> CGFloat {
+    unsafe {
+        let window: id = msg_send![view, window];
+
+        match window {
+            window if window == nil => {
+                let screen: id = msg_send![class!(NSScreen), mainScreen];
+                msg_send![screen, backingScaleFactor]
+            },
+
+            window => msg_send![window, backingScaleFactor]
+        }
+    }
+}
+
+/// Called when the view's backing properties (e.g, scale factor, when moving between displays)
+/// change. If the view is rasterized with a tracked scale, we update the rasterization scale to
+/// match so that content doesn't go blurry.
+extern fn did_change_backing_properties(this: &Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), viewDidChangeBackingProperties];
+
+        let tracks_scale: BOOL = *this.get_ivar(RASTERIZATION_TRACKS_SCALE);
+
+        if tracks_scale == YES {
+            let layer: id = msg_send![this, layer];
+            let _: () = msg_send![layer, setRasterizationScale:backing_scale_factor(this)];
+        }
+    }
+}
+
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
@@ -101,8 +136,10 @@ pub(crate) fn register_view_class() -> *const Class {
         decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
         decl.add_method(sel!(updateLayer), update_layer as extern fn(&Object, _));
         decl.add_method(sel!(wantsUpdateLayer), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+        decl.add_method(sel!(viewDidChangeBackingProperties), did_change_backing_properties as extern fn(&Object, _));
 
         decl.add_ivar::<id>(BACKGROUND_COLOR);
+        decl.add_ivar::<BOOL>(RASTERIZATION_TRACKS_SCALE);
     
         VIEW_CLASS = decl.register();
     });
@@ -118,6 +155,7 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
         // It's expected that this doesn't move.
         decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
         decl.add_ivar::<id>(BACKGROUND_COLOR);
+        decl.add_ivar::<BOOL>(RASTERIZATION_TRACKS_SCALE);
         
         decl.add_method(
             sel!(updateLayer),
@@ -134,6 +172,11 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
             enforce_normalcy as extern fn(&Object, _) -> BOOL
         );
 
+        decl.add_method(
+            sel!(viewDidChangeBackingProperties),
+            did_change_backing_properties as extern fn(&Object, _)
+        );
+
         // Drag and drop operations (e.g, accepting files)
         decl.add_method(
             sel!(draggingEntered:),
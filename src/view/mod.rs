@@ -44,10 +44,10 @@
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
+use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSString};
 use crate::color::Color;
 use crate::layer::Layer;
-use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension, UserInterfaceLayoutDirection};
 use crate::utils::properties::ObjcProperty;
 
 #[cfg(target_os = "macos")]
@@ -78,6 +78,7 @@ pub use traits::ViewDelegate;
 
 pub(crate) static BACKGROUND_COLOR: &str = "alchemyBackgroundColor";
 pub(crate) static VIEW_DELEGATE_PTR: &str = "rstViewDelegatePtr";
+pub(crate) static RASTERIZATION_TRACKS_SCALE: &str = "rstRasterizationTracksScale";
 
 /// A clone-able handler to a `ViewController` reference in the Objective C runtime. We use this
 /// instead of a stock `View` for easier recordkeeping, since it'll need to hold the `View` on that
@@ -242,6 +243,107 @@ impl<T> View<T> {
         });
     }
 
+    /// Forces the layout direction for this view (and, on iOS, its subviews). Leading and
+    /// trailing anchors follow this, so constraints built with them mirror automatically - if
+    /// you're building layouts that should flip for right-to-left languages, use those rather
+    /// than left/right.
+    ///
+    /// You typically don't need to call this; by default, views follow the direction of the
+    /// user's preferred language.
+    pub fn set_user_interface_layout_direction(&self, direction: UserInterfaceLayoutDirection) {
+        #[cfg(target_os = "macos")]
+        self.objc.with_mut(|obj| unsafe {
+            let direction: NSInteger = direction.into();
+            let _: () = msg_send![obj, setUserInterfaceLayoutDirection:direction];
+        });
+
+        // UIKit doesn't let you set the direction directly; it's derived from the semantic
+        // content attribute instead.
+        #[cfg(target_os = "ios")]
+        self.objc.with_mut(|obj| unsafe {
+            let attribute: NSInteger = match direction {
+                UserInterfaceLayoutDirection::LeftToRight => 3,
+                UserInterfaceLayoutDirection::RightToLeft => 4,
+                UserInterfaceLayoutDirection::Unknown(_) => 0
+            };
+
+            let _: () = msg_send![obj, setSemanticContentAttribute:attribute];
+        });
+    }
+
+    /// Sets whether this view's layer (and everything below it) should be rasterized - i.e,
+    /// rendered once into a bitmap that's then reused for compositing. For a complex subtree that
+    /// doesn't change, but moves around as a unit (animating, scrolling), this can dramatically
+    /// cut the cost of each frame.
+    ///
+    /// The tradeoff: the bitmap costs memory (roughly width * height * scale^2 * 4 bytes), and it
+    /// has to be re-rendered whenever anything in the subtree changes - so rasterizing content
+    /// that updates frequently is slower, not faster. Transforms that scale the view up will also
+    /// make the bitmap look soft.
+    ///
+    /// The rasterization scale is set to the backing scale factor of the screen the view is on,
+    /// and on macOS it's kept in sync if the view moves to a screen with a different scale. Use
+    /// `set_rasterized_with_scale()` if you need something else.
+    pub fn set_rasterized(&self, rasterized: bool) {
+        #[cfg(target_os = "macos")]
+        self.set_tracks_rasterization_scale(rasterized);
+
+        #[cfg(target_os = "macos")]
+        let scale = self.objc.get(|obj| macos::backing_scale_factor(obj));
+
+        #[cfg(target_os = "ios")]
+        let scale = self.objc.get(|obj| unsafe {
+            let window: id = msg_send![obj, window];
+
+            let screen: id = match window {
+                window if window == nil => msg_send![objc::class!(UIScreen), mainScreen],
+                window => msg_send![window, screen]
+            };
+
+            let scale: core_graphics::base::CGFloat = msg_send![screen, scale];
+            scale
+        });
+
+        self.layer.set_rasterization_scale(scale as f64);
+        self.layer.set_should_rasterize(rasterized);
+    }
+
+    /// Like `set_rasterized()`, but rasterizes at a fixed `scale` instead of following the
+    /// screen's backing scale factor. A lower scale trades sharpness for memory.
+    pub fn set_rasterized_with_scale(&self, rasterized: bool, scale: f64) {
+        #[cfg(target_os = "macos")]
+        self.set_tracks_rasterization_scale(false);
+        self.layer.set_rasterization_scale(scale);
+        self.layer.set_should_rasterize(rasterized);
+    }
+
+    /// Flags whether the view should update its rasterization scale when its backing properties
+    /// change. Views backed by classes without the ivar (e.g, list rows) just don't track it.
+    #[cfg(target_os = "macos")]
+    fn set_tracks_rasterization_scale(&self, tracks: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            if (&*obj).class().instance_variable(RASTERIZATION_TRACKS_SCALE).is_some() {
+                (&mut *obj).set_ivar(RASTERIZATION_TRACKS_SCALE, match tracks {
+                    true => YES,
+                    false => NO
+                });
+            }
+        });
+    }
+
+    /// Returns the layout direction this view is actually using, taking into account system
+    /// settings and anything set via `set_user_interface_layout_direction()`.
+    pub fn user_interface_layout_direction(&self) -> UserInterfaceLayoutDirection {
+        self.objc.get(|obj| unsafe {
+            #[cfg(target_os = "macos")]
+            let direction: NSInteger = msg_send![obj, userInterfaceLayoutDirection];
+
+            #[cfg(target_os = "ios")]
+            let direction: NSInteger = msg_send![obj, effectiveUserInterfaceLayoutDirection];
+
+            direction.into()
+        })
+    }
 }
 
 impl<T> Layout for View<T> {
@@ -269,3 +371,55 @@ impl<T> Drop for View<T> {
         }
     }
 }
+
+#[cfg(all(test, target_os = "macos"))]
+mod tests {
+    use core_graphics::geometry::CGRect;
+    use objc::{msg_send, sel, sel_impl};
+
+    use crate::foundation::NSInteger;
+    use crate::geometry::Rect;
+    use crate::layout::{Layout, LayoutConstraint, UserInterfaceLayoutDirection};
+    use crate::view::View;
+
+    #[test]
+    fn test_layout_direction_round_trip() {
+        for direction in [
+            UserInterfaceLayoutDirection::LeftToRight,
+            UserInterfaceLayoutDirection::RightToLeft,
+            UserInterfaceLayoutDirection::Unknown(7)
+        ] {
+            let value: NSInteger = direction.into();
+            assert_eq!(UserInterfaceLayoutDirection::from(value), direction);
+        }
+    }
+
+    #[test]
+    fn test_right_to_left_mirrors_leading() {
+        let parent = View::new();
+        parent.set_translates_autoresizing_mask_into_constraints(true);
+        parent.set_frame(Rect::new(0., 0., 100., 100.));
+
+        let child = View::new();
+        parent.add_subview(&child);
+
+        LayoutConstraint::activate(&[
+            child.top.constraint_equal_to(&parent.top),
+            child.leading.constraint_equal_to(&parent.leading),
+            child.width.constraint_equal_to_constant(20.),
+            child.height.constraint_equal_to_constant(20.)
+        ]);
+
+        parent.set_user_interface_layout_direction(UserInterfaceLayoutDirection::RightToLeft);
+        child.set_user_interface_layout_direction(UserInterfaceLayoutDirection::RightToLeft);
+        assert_eq!(parent.user_interface_layout_direction(), UserInterfaceLayoutDirection::RightToLeft);
+
+        parent.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, layoutSubtreeIfNeeded];
+        });
+
+        let frame: CGRect = child.objc.get(|obj| unsafe { msg_send![obj, frame] });
+        assert_eq!(frame.origin.x, 80.);
+        assert_eq!(frame.size.width, 20.);
+    }
+}
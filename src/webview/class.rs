@@ -18,6 +18,25 @@ use crate::webview::actions::{NavigationAction, NavigationResponse};//, OpenPane
 //use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
 use crate::utils::load;
 
+#[cfg(target_os = "macos")]
+use objc::runtime::BOOL;
+
+#[cfg(target_os = "macos")]
+use objc_id::ShareId;
+
+#[cfg(target_os = "macos")]
+use crate::foundation::NSUInteger;
+
+#[cfg(target_os = "macos")]
+use crate::webview::WEBVIEW_DROP_HANDLER;
+
+#[cfg(target_os = "macos")]
+use crate::dragdrop::{DragInfo, DragOperation, DropItem};
+
+/// The signature of `forward_drop`, once it's been specialized for a delegate type.
+#[cfg(target_os = "macos")]
+pub(crate) type DropHandler = fn(&Object, Vec<DropItem>) -> bool;
+
 /// Called when an `alert()` from the underlying `WKWebView` is fired. Will call over to your
 /// `WebViewController`, where you should handle the event.
 extern fn alert<T: WebViewDelegate>(_: &Object, _: Sel, _: id, _: id, _: id, complete: id) {
@@ -157,6 +176,79 @@ extern fn handle_download<T: WebViewDelegate>(this: &Object, _: Sel, download: i
     });
 }
 
+/// Forwards a drop on to the delegate for this webview. The webview class is shared by every
+/// delegate type, so rather than being generic itself, it stores a pointer to this function
+/// (specialized for the right `T`) alongside the delegate pointer.
+#[cfg(target_os = "macos")]
+pub(crate) fn forward_drop<T: WebViewDelegate>(this: &Object, items: Vec<DropItem>) -> bool {
+    load::<T>(this, WEBVIEW_DELEGATE_PTR).did_receive_drop(items)
+}
+
+/// Returns the drop handler for this webview, if it's opted in to intercepting drops. If it
+/// hasn't, the pointer is left at zero and everything falls through to `WKWebView`.
+#[cfg(target_os = "macos")]
+fn drop_handler(this: &Object) -> Option<DropHandler> {
+    let ptr: usize = unsafe { *this.get_ivar(WEBVIEW_DROP_HANDLER) };
+
+    match ptr {
+        0 => None,
+        ptr => Some(unsafe { std::mem::transmute::<usize, DropHandler>(ptr) })
+    }
+}
+
+/// Called when a drag/drop operation has entered the webview. If we're intercepting drops and
+/// the page wouldn't otherwise accept what's being dragged, we accept it as a copy so that it can
+/// be handed to the delegate.
+#[cfg(target_os = "macos")]
+extern fn dragging_entered(this: &mut Object, _: Sel, info: id) -> NSUInteger {
+    let operation: NSUInteger = unsafe { msg_send![super(this, class!(WKWebView)), draggingEntered:info] };
+    accepted_operation(this, info, operation)
+}
+
+/// Called as a drag/drop operation moves around the webview. See `dragging_entered`.
+#[cfg(target_os = "macos")]
+extern fn dragging_updated(this: &mut Object, _: Sel, info: id) -> NSUInteger {
+    let operation: NSUInteger = unsafe { msg_send![super(this, class!(WKWebView)), draggingUpdated:info] };
+    accepted_operation(this, info, operation)
+}
+
+/// Shared logic for `dragging_entered` and `dragging_updated`.
+#[cfg(target_os = "macos")]
+fn accepted_operation(this: &Object, info: id, operation: NSUInteger) -> NSUInteger {
+    let none: NSUInteger = DragOperation::None.into();
+
+    if operation != none || drop_handler(this).is_none() {
+        return operation;
+    }
+
+    let info = DragInfo { info: unsafe { ShareId::from_ptr(info) } };
+
+    match info.get_items().is_empty() {
+        true => none,
+        false => DragOperation::Copy.into()
+    }
+}
+
+/// Called when a drag/drop operation is dropped on the webview. The delegate gets first crack at
+/// it; if it declines, WebKit handles the drop as usual.
+#[cfg(target_os = "macos")]
+extern fn perform_drag_operation(this: &mut Object, _: Sel, info: id) -> BOOL {
+    if let Some(handler) = drop_handler(this) {
+        let items = DragInfo { info: unsafe { ShareId::from_ptr(info) } }.get_items();
+
+        if !items.is_empty() && handler(this, items) {
+            // Let the page know the drag is over, so it can reset any hover/drop-zone state.
+            unsafe {
+                let _: () = msg_send![super(this, class!(WKWebView)), draggingExited:info];
+            }
+
+            return YES;
+        }
+    }
+
+    unsafe { msg_send![super(this, class!(WKWebView)), performDragOperation:info] }
+}
+
 /// Registers an `NSViewController` that we effectively turn into a `WebViewController`. Acts as
 /// both a subclass of `NSViewController` and a delegate of the held `WKWebView` (for the various
 /// varieties of delegates needed there).
@@ -173,6 +265,34 @@ pub fn register_webview_class() -> *const Class {
     unsafe { VIEW_CLASS }
 }
 
+/// Registers a `WKWebView` subclass that can hold a pointer back to a `WebViewDelegate` (and a
+/// handler that knows its type), which it uses for intercepting drag and drop. The pointers are
+/// only set if the `WebViewConfig` opted in to intercepting drops.
+pub fn register_webview_class_with_delegate() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(WKWebView);
+        let mut decl = ClassDecl::new("RSTWebViewWithDelegate", superclass).unwrap();
+
+        #[cfg(target_os = "macos")]
+        {
+            decl.add_ivar::<usize>(WEBVIEW_DELEGATE_PTR);
+            decl.add_ivar::<usize>(WEBVIEW_DROP_HANDLER);
+
+            // NSDraggingDestination
+            decl.add_method(sel!(draggingEntered:), dragging_entered as extern fn(&mut Object, _, _) -> NSUInteger);
+            decl.add_method(sel!(draggingUpdated:), dragging_updated as extern fn(&mut Object, _, _) -> NSUInteger);
+            decl.add_method(sel!(performDragOperation:), perform_drag_operation as extern fn(&mut Object, _, _) -> BOOL);
+        }
+
+        VIEW_CLASS = decl.register();
+    });
+
+    unsafe { VIEW_CLASS }
+}
+
 /// Registers an `NSViewController` that we effectively turn into a `WebViewController`. Acts as
 /// both a subclass of `NSViewController` and a delegate of the held `WKWebView` (for the various
 /// varieties of delegates needed there).
@@ -7,6 +7,7 @@ use objc::{class, msg_send, sel, sel_impl};
 
 use crate::foundation::{id, YES, NO, NSString, NSInteger};
 use crate::webview::enums::InjectAt;
+use crate::webview::process_pool::ProcessPool;
 
 /// A wrapper for `WKWebViewConfiguration`. Holds (retains) pointers for the Objective-C runtime 
 /// where everything lives.
@@ -14,7 +15,8 @@ use crate::webview::enums::InjectAt;
 pub struct WebViewConfig {
     pub objc: Id<Object>,
     pub handlers: Vec<String>,
-    pub protocols: Vec<String>
+    pub protocols: Vec<String>,
+    pub intercepts_drops: bool
 }
 
 impl Default for WebViewConfig {
@@ -28,7 +30,8 @@ impl Default for WebViewConfig {
         WebViewConfig {
             objc: config,
             handlers: vec![],
-            protocols: vec![]
+            protocols: vec![],
+            intercepts_drops: false
         }
     }
 }
@@ -63,6 +66,33 @@ impl WebViewConfig {
         self.protocols.push(protocol_name.to_string());
     }
 
+    /// Sets the process pool for webviews created with this configuration. Webviews that share a
+    /// pool share web content processes (and thus cookies and session state); see the
+    /// `ProcessPool` docs for more.
+    pub fn set_process_pool(&mut self, pool: &ProcessPool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setProcessPool:&*pool.0];
+        }
+    }
+
+    /// Returns the process pool for this configuration. This is useful if you've got an existing
+    /// configuration and want to share its pool with another.
+    pub fn process_pool(&self) -> ProcessPool {
+        ProcessPool::with(unsafe {
+            msg_send![&*self.objc, processPool]
+        })
+    }
+
+    /// Opts in to handling drag and drop yourself. When enabled, files, URLs or text dropped onto
+    /// the `WebView` are first passed to `WebViewDelegate::did_receive_drop()`; if that returns
+    /// `true`, the drop is considered handled and the page won't navigate to whatever was
+    /// dropped.
+    ///
+    /// This only has an effect for webviews created with a delegate, and is macOS-only.
+    pub fn set_intercepts_drops(&mut self, intercepts: bool) {
+        self.intercepts_drops = intercepts;
+    }
+
     /// Enables access to the underlying inspector view for `WKWebView`.
     pub fn enable_developer_extras(&mut self) {
         let key = NSString::new("developerExtrasEnabled");
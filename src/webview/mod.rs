@@ -16,7 +16,7 @@
 use core_graphics::geometry::CGRect;
 
 use objc_id::ShareId;
-use objc::runtime::Object;
+use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel, sel_impl};
 
 use crate::foundation::{id, nil, YES, NO, NSString};
@@ -35,16 +35,24 @@ mod enums;
 pub use enums::*;
 
 pub(crate) mod class;
-use class::{register_webview_class, register_webview_delegate_class};
-//pub(crate) mod process_pool;
+use class::{register_webview_class, register_webview_class_with_delegate, register_webview_delegate_class};
+
+#[cfg(target_os = "macos")]
+use class::{DropHandler, forward_drop};
+
+mod process_pool;
+pub use process_pool::ProcessPool;
 
 mod mimetype;
 mod traits;
 pub use traits::WebViewDelegate;
 
 pub(crate) static WEBVIEW_DELEGATE_PTR: &str = "rstWebViewDelegatePtr";
+#[cfg(target_os = "macos")]
+pub(crate) static WEBVIEW_DROP_HANDLER: &str = "rstWebViewDropHandler";
 
 fn allocate_webview(
+    class: *const Class,
     mut config: WebViewConfig,
     objc_delegate: Option<&Object>
 ) -> id {
@@ -76,7 +84,7 @@ fn allocate_webview(
         }
 
         let zero: CGRect = Rect::zero().into();
-        let webview_alloc: id = msg_send![register_webview_class(), alloc];
+        let webview_alloc: id = msg_send![class, alloc];
         let webview: id = msg_send![webview_alloc, initWithFrame:zero configuration:configuration];
 
         #[cfg(target_os = "macos")]
@@ -189,7 +197,7 @@ impl WebView {
 
     /// Returns a default `WebView`, suitable for customizing and displaying.
     pub fn new(config: WebViewConfig) -> Self {
-        let view = allocate_webview(config, None);
+        let view = allocate_webview(register_webview_class(), config, None);
         WebView::init(view)
     }
 }
@@ -207,7 +215,25 @@ impl<T> WebView<T> where T: WebViewDelegate + 'static {
             ShareId::from_ptr(objc_delegate)
         };
 
-        let view = allocate_webview(config, Some(&objc_delegate));
+        #[cfg(target_os = "macos")]
+        let intercepts_drops = config.intercepts_drops;
+
+        let view = allocate_webview(register_webview_class_with_delegate(), config, Some(&objc_delegate));
+
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let ptr: *const T = &*delegate;
+            let handler: DropHandler = forward_drop::<T>;
+
+            let (ptr, handler) = match intercepts_drops {
+                true => (ptr as usize, handler as usize),
+                false => (0, 0)
+            };
+
+            (&mut *view).set_ivar(WEBVIEW_DELEGATE_PTR, ptr);
+            (&mut *view).set_ivar(WEBVIEW_DROP_HANDLER, handler);
+        }
+
         let mut view = WebView::init(view);
 
         &delegate.did_load(view.clone_as_handle()); 
@@ -308,7 +334,17 @@ impl<T> Drop for WebView<T> {
                 let _: () = msg_send![&*obj, setNavigationDelegate:nil];
                 let _: () = msg_send![&*obj, setUIDelegate:nil];
             });
-            
+
+            // The view can outlive us, so make sure a late drag and drop can't reach the
+            // delegate after it's been freed.
+            #[cfg(target_os = "macos")]
+            if self.delegate.is_some() {
+                self.objc.with_mut(|obj| unsafe {
+                    (&mut *obj).set_ivar::<usize>(WEBVIEW_DELEGATE_PTR, 0);
+                    (&mut *obj).set_ivar::<usize>(WEBVIEW_DROP_HANDLER, 0);
+                });
+            }
+
             self.remove_from_superview();
         }
     }
@@ -1,44 +1,44 @@
-//! Implements a shared `WKProcessPool`, so that multiple webviews (should they be needed) properly
-//! share cookies and the like. It also, if you opt in to the feature flag, enables a download
-//! delegate that's sadly a private API... in 2020.
+//! A wrapper for `WKProcessPool`, which controls which web content process(es) a `WebView` runs
+//! in.
 //!
-//! If you use that feature, there are no guarantees you'll be accepted into the App Store.
-
-use std::sync::Once;
-use std::ffi::c_void;
-
-use block::Block;
-
-use cocoa::foundation::{NSRect, NSPoint, NSSize, NSString, NSArray, NSInteger};
+//! Each `WebViewConfig` gets its own pool by default. If you have multiple webviews that should
+//! behave like tabs in the same browser (e.g, sharing login state), create one `ProcessPool` and
+//! hand it to each of their configurations. If they should be deliberately isolated, give each
+//! its own pool (which is what happens if you do nothing).
+//!
+//! Note that on newer systems (macOS 12+, iOS 15+) WebKit manages process sharing itself, and
+//! session state is tied more to the website data store than the process pool - pools are still
+//! respected where they matter, but isolation should not be treated as a security boundary.
 
-use objc::declare::ClassDecl;
-use objc::runtime::{Class, Object, Sel, BOOL};
+use objc_id::ShareId;
+use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO};
-use crate::webview::traits::WebViewController;
+use crate::foundation::id;
+
+/// A wrapper for `WKProcessPool`. This is cheap to clone; clones refer to the same pool, and the
+/// pool lives for as long as any handle (or any `WebView` using it) does.
+#[derive(Clone, Debug)]
+pub struct ProcessPool(pub ShareId<Object>);
 
-extern fn download_delegate(this: &Object, _: Sel) -> id {
-    println!("YO!");
-    unsafe {
-    NSString::alloc(nil).init_str("")
+impl Default for ProcessPool {
+    fn default() -> Self {
+        ProcessPool::new()
     }
 }
 
-pub fn register_process_pool() -> *const Object {
-    static mut PROCESS_POOL: *const Object = 0 as *const Object;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = Class::get("WKProcessPool").unwrap();
-        let mut decl = ClassDecl::new("RSTWebViewProcessPool", superclass).unwrap();
-
-        //decl.add_ivar::<id>(DOWNLOAD_DELEGATE_PTR);
-        decl.add_method(sel!(_downloadDelegate), download_delegate as extern fn(&Object, _) -> id);
-
-        //PROCESS_POOL = decl.register();
-        PROCESS_POOL = msg_send![decl.register(), new];
-    });
+impl ProcessPool {
+    /// Creates and returns a new, empty process pool.
+    pub fn new() -> Self {
+        ProcessPool(unsafe {
+            ShareId::from_retained_ptr(msg_send![class!(WKProcessPool), new])
+        })
+    }
 
-    unsafe { PROCESS_POOL }
+    /// Wraps and retains an existing `WKProcessPool`.
+    pub(crate) fn with(pool: id) -> Self {
+        ProcessPool(unsafe {
+            ShareId::from_ptr(pool)
+        })
+    }
 }
@@ -2,6 +2,8 @@
 //! `WKWebView`. It allows you to do things such as handle opening a file (for uploads or
 //! in-browser-processing), handling navigation actions or JS message callbacks, and so on.
 
+#[cfg(target_os = "macos")]
+use crate::dragdrop::DropItem;
 use crate::webview::WebView;
 use crate::webview::actions::{NavigationAction, NavigationResponse, OpenPanelParameters};
 use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
@@ -69,4 +71,16 @@ pub trait WebViewDelegate {
     fn run_save_panel<F: Fn(bool, Option<String>) + 'static>(&self, _suggested_filename: &str, handler: F) {
         handler(false, None);
     }
+
+    /// Called when files, URLs or text are dropped onto the `WebView`. Return `true` if you've
+    /// handled the drop (e.g, by passing it along to the page via a script call), or `false` to
+    /// let WebKit handle it as it normally would - which, for files and links, typically means
+    /// navigating to them.
+    ///
+    /// This is only called if you've opted in via `WebViewConfig::set_intercepts_drops()`, and
+    /// is macOS-only.
+    #[cfg(target_os = "macos")]
+    fn did_receive_drop(&self, _items: Vec<DropItem>) -> bool {
+        false
+    }
 }